@@ -1,7 +1,19 @@
-use rrr::record::{HashedRecordKey, RecordReadVersionSuccess};
+use std::{fmt, path::PathBuf, sync::Arc, time::Instant};
+
+use rrr::record::{
+    HashedRecordKey, RecordName, RecordReadVersionSuccess, RecordVersion, SuccessionNonce,
+};
+use serde::Deserialize;
 use strum::Display;
 
-use crate::component::ComponentId;
+use crate::{component::ComponentId, config::Config, registry::SearchLimit};
+
+/// A successfully loaded version of a record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadedRecordVersion {
+    pub record_version: RecordVersion,
+    pub record: Arc<RecordReadVersionSuccess>, // Arc'd for cheaper cloning
+}
 
 /// These are applied to all components unconditionally using the `Component::update` method.
 #[derive(Debug, Clone, PartialEq, Display)]
@@ -17,30 +29,199 @@ pub enum ComponentMessage {
     },
     ShowError {
         error: String,
+        /// The generation of the request to open a record that failed, if the error comes from
+        /// one.
+        open_generation: Option<u64>,
+    },
+    Navigate(Navigation),
+    ToggleBreadcrumbMask,
+    /// Sent after text was copied to the clipboard.
+    ClipboardCopy {
+        /// When the clipboard is going to be cleared, if ever.
+        clear_instant: Option<Instant>,
     },
+    /// Sent after the clipboard was cleared, following a previous copy.
+    ClipboardClear,
     RecordOpen {
+        /// Identifies the request to open the record, so that the results of superseded requests
+        /// can be ignored.
+        generation: u64,
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        /// The record whose succession nonce was used to find this record, if any.
+        parent: Option<HashedRecordKey>,
+        /// All listed versions of the record in ascending order, or empty if the record was not
+        /// found.
+        versions: Vec<RecordVersion>,
+        /// The latest version of the record, if it was found. Other versions are loaded on demand.
+        latest_version: Option<LoadedRecordVersion>,
+        /// The succession nonce of the record, if it was found.
+        succession_nonce: Option<Arc<SuccessionNonce>>,
+        /// The search limit that ended the listing of the versions short of an existing version,
+        /// if any.
+        search_limit: Option<SearchLimit>,
+    },
+    /// Sent when the request to open a record identified by `generation` enters a new stage.
+    RecordOpenProgress {
+        generation: u64,
+        stage: RecordOpenStage,
+    },
+    /// Sent when the succession nonce of a record had to be derived again, so that it can be
+    /// cached if the record is still opened.
+    SuccessionNonceDerive {
         hashed_record_key: HashedRecordKey,
-        read_result: Option<RecordReadVersionSuccess>,
+        succession_nonce: Arc<SuccessionNonce>,
+    },
+    /// Sent when a version of an opened record, requested by selecting it, finishes loading.
+    RecordVersionLoad {
+        hashed_record_key: HashedRecordKey,
+        record_version: RecordVersion,
+        result: Result<LoadedRecordVersion, String>,
+    },
+    /// Sent when the displayed version of the opened record, or the version it is compared
+    /// against, changes.
+    DisplayedVersionChange,
+    /// Sent when a record version export finishes, with the path of the exported data.
+    ExportFinish {
+        result: Result<PathBuf, String>,
+    },
+    /// Sent when saving a new record version finishes, with the version that was written.
+    RecordSave {
+        result: Result<RecordVersion, String>,
+    },
+    /// Sent after the configuration was changed in the settings.
+    ConfigChange(Arc<Config>),
+    /// Requests a record that has already been found to be opened again.
+    RecordReopen {
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<HashedRecordKey>,
     },
 }
 
 /// Messages generated by components, handled by the app.
-#[derive(Debug, Clone, PartialEq, Display)]
+/// Variants that are not skipped may be bound to keys in the configuration.
+#[derive(Debug, Clone, PartialEq, Display, Deserialize)]
 pub enum Action {
+    #[serde(skip)]
     Tick,
+    #[serde(skip)]
     Render,
+    #[serde(skip)]
     Resize(u16, u16),
-    #[expect(unused)]
     Suspend,
+    #[serde(skip)]
     Resume,
     Quit,
     ClearScreen,
     FocusChange(FocusChange),
+    /// Move the focus to the component with the given ID.
+    #[serde(skip)]
+    FocusComponent(ComponentId),
+    /// Open the previously opened record.
+    NavigateBack,
+    /// Open the record that was opened before navigating back.
+    NavigateForward,
+    /// Open the root record.
+    NavigateHome,
+    /// Show or hide the record names in the breadcrumb.
+    ToggleBreadcrumbMask,
+    /// Show or hide the help overlay.
+    ToggleHelp,
+    /// Place the text into the clipboard, using the terminal's OSC 52 support.
+    /// The clipboard is cleared after the configured timeout.
+    #[serde(skip)]
+    CopyToClipboard(String),
     /// Send a message to all other components.
+    #[serde(skip)]
     BroadcastMessage(ComponentMessage),
+    /// Apply the changed configuration where possible while running.
+    #[serde(skip)]
+    ApplyConfig(Arc<Config>),
+}
+
+impl Action {
+    /// Describes what the action does, as listed in the help overlay.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::Suspend => "Suspend the application".into(),
+            Self::Quit => "Quit the application".into(),
+            Self::ClearScreen => "Redraw the screen".into(),
+            Self::FocusChange(FocusChange { direction, scope }) => {
+                let target = match (direction, scope) {
+                    (FocusChangeDirection::Forward, FocusChangeScope::HorizontalAndVertical) => {
+                        "the next component"
+                    }
+                    (FocusChangeDirection::Backward, FocusChangeScope::HorizontalAndVertical) => {
+                        "the previous component"
+                    }
+                    (FocusChangeDirection::Forward, FocusChangeScope::Horizontal) => {
+                        "the next sibling"
+                    }
+                    (FocusChangeDirection::Backward, FocusChangeScope::Horizontal) => {
+                        "the previous sibling"
+                    }
+                    (FocusChangeDirection::Forward, FocusChangeScope::Vertical) => {
+                        "the first inner component"
+                    }
+                    (FocusChangeDirection::Backward, FocusChangeScope::Vertical) => {
+                        "the enclosing component"
+                    }
+                };
+
+                format!("Focus {target}")
+            }
+            Self::NavigateBack => "Open the previously opened record".into(),
+            Self::NavigateForward => "Open the record opened before navigating back".into(),
+            Self::NavigateHome => "Open the root record".into(),
+            Self::ToggleBreadcrumbMask => "Show or hide the record names in the breadcrumb".into(),
+            Self::ToggleHelp => "Show or hide this help".into(),
+            action => action.to_string(),
+        }
+    }
+}
+
+/// A stage of opening a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordOpenStage {
+    /// Deriving a succession nonce with the registry's KDF, either of the parent record, or of
+    /// the found record.
+    DerivingNonce,
+    /// Hashing the record key.
+    HashingKey,
+    /// Listing the versions of the record, resolving hash collisions with up to `attempts`
+    /// attempts per fragment.
+    ListingVersions { attempts: u64 },
+    /// Loading the `version`-th out of `versions` versions, starting at 1.
+    LoadingVersion { version: usize, versions: usize },
+    /// Checking whether larger search limits would list more versions of the record.
+    ProbingSearchLimits,
+}
+
+impl fmt::Display for RecordOpenStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DerivingNonce => write!(f, "Deriving nonce"),
+            Self::HashingKey => write!(f, "Hashing key"),
+            Self::ListingVersions { attempts } => {
+                write!(f, "Listing versions (up to {attempts} attempts)")
+            }
+            Self::LoadingVersion { version, versions } => {
+                write!(f, "Loading version {version} of {versions}")
+            }
+            Self::ProbingSearchLimits => write!(f, "Checking the search limits"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum Navigation {
+    Back,
+    Forward,
+    Home,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Deserialize)]
 pub enum FocusChangeDirection {
     Forward,
     Backward,
@@ -49,7 +230,7 @@ pub enum FocusChangeDirection {
 /// Describes the set of components considered for focus.
 /// The horizontal scope is all the siblings of the currently focused component.
 /// The vertical scope is all the parent or the first child
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Deserialize)]
 pub enum FocusChangeScope {
     Horizontal,
     Vertical,
@@ -68,7 +249,7 @@ impl FocusChangeScope {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct FocusChange {
     pub direction: FocusChangeDirection,
     pub scope: FocusChangeScope,
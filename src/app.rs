@@ -5,19 +5,27 @@ use std::{
 };
 
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use ratatui::prelude::Rect;
+use crossterm::{
+    clipboard::CopyToClipboard,
+    event::{KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+};
+use itertools::Itertools;
+use ratatui::{layout::Position, prelude::Rect};
 use tokio::sync::mpsc;
 use tracing::instrument;
 
 use crate::{
-    action::{Action, ComponentMessage, FocusChange, FocusChangeDirection, FocusChangeScope},
+    action::{
+        Action, ComponentMessage, FocusChange, FocusChangeDirection, FocusChangeScope, Navigation,
+    },
     args::Args,
     component::{
-        self, ComponentId, ComponentIdPath, DefaultDrawableComponent, DrawContext,
-        HandleEventSuccess, find_component_by_id_mut,
+        self, Component, ComponentId, ComponentIdPath, DefaultDrawableComponent, DrawContext,
+        HandleEventSuccess, KeyHints, find_component_by_id_mut,
     },
-    components::main_view::MainView,
+    components::{help_overlay::HelpOverlay, main_view::MainView, scroll_view::ScrollView},
+    config::Config,
+    keybindings::{KeyBindings, Mode, format_key_sequence, normalize_key_event},
     tui::{Event, Tui},
 };
 
@@ -27,33 +35,61 @@ pub struct App {
     frame_rate: f64,
     should_quit: bool,
     should_suspend: bool,
-    last_tick_key_events: Vec<KeyEvent>,
+    mode: Mode,
+    keybindings: KeyBindings,
+    key_sequence_timeout: Duration,
+    /// The keys pressed so far of a key sequence that has not been completed yet.
+    pending_key_events: Vec<KeyEvent>,
+    /// When the last of the pending keys was pressed.
+    pending_key_events_instant: Option<Instant>,
+    /// Whether the help overlay is displayed over the user interface.
+    help_visible: bool,
+    help_scroll_view: ScrollView,
     action_tx: mpsc::UnboundedSender<Action>,
     action_rx: mpsc::UnboundedReceiver<Action>,
     root_component: Box<dyn DefaultDrawableComponent>,
     focus_path: ComponentIdPath,
+    /// Whether mouse events are captured, preventing the terminal from selecting text.
+    mouse: bool,
+    /// The areas components were drawn into during the last render, in the order they were drawn.
+    drawn_areas: Vec<(ComponentId, Rect)>,
     first_render_instant: Option<Instant>,
+    clipboard_clear_timeout: Option<Duration>,
+    /// When the clipboard is to be cleared, if text was copied into it.
+    clipboard_clear_instant: Option<Instant>,
 }
 
 impl App {
     #[instrument]
-    pub async fn new(args: &Arc<Args>) -> Result<Self> {
+    pub async fn new(args: &Arc<Args>, config: &Arc<Config>) -> Result<Self> {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
         let mut app = Self {
-            tick_rate: args.tick_rate,
-            frame_rate: args.frame_rate,
+            tick_rate: config.tick_rate,
+            frame_rate: config.frame_rate,
             should_quit: false,
             should_suspend: false,
-            last_tick_key_events: Vec::new(),
-            root_component: Box::new(MainView::new(ComponentId::root(), &action_tx, args).await?),
+            mode: Mode::default(),
+            keybindings: config.keybindings.clone(),
+            key_sequence_timeout: Duration::from_secs_f64(config.key_sequence_timeout),
+            pending_key_events: Vec::new(),
+            pending_key_events_instant: None,
+            help_visible: false,
+            help_scroll_view: ScrollView::new(ComponentId::new()),
+            root_component: Box::new(
+                MainView::new(ComponentId::root(), &action_tx, args, config).await?,
+            ),
             focus_path: Default::default(),
+            mouse: config.ui.mouse,
+            drawn_areas: Vec::new(),
             action_tx,
             action_rx,
             first_render_instant: None,
+            clipboard_clear_timeout: Self::get_clipboard_clear_timeout(config),
+            clipboard_clear_instant: None,
         };
 
         // Ensure a valid initial focus.
-        if !app.root_component.is_focusable() {
+        if !component::accepts_focus(&*app.root_component) {
             app.change_focus(FocusChange {
                 direction: FocusChangeDirection::Forward,
                 scope: FocusChangeScope::HorizontalAndVertical,
@@ -66,7 +102,7 @@ impl App {
     #[instrument(skip(self))]
     pub async fn run(&mut self) -> Result<()> {
         let mut tui = Tui::new(tracing::Span::current())?
-            // .mouse(true) // uncomment this line to enable mouse support
+            .mouse(self.mouse)
             .tick_rate(self.tick_rate)
             .frame_rate(self.frame_rate);
         tui.enter()?;
@@ -79,9 +115,13 @@ impl App {
                 tui.suspend()?;
                 action_tx.send(Action::Resume)?;
                 action_tx.send(Action::ClearScreen)?;
-                // tui.mouse(true);
                 tui.enter()?;
             } else if self.should_quit {
+                // Do not leave copied secrets behind.
+                if self.clipboard_clear_instant.is_some() {
+                    Self::write_clipboard(&mut tui, "")?;
+                }
+
                 tui.stop()?;
                 break;
             }
@@ -102,105 +142,164 @@ impl App {
             Event::Tick => action_tx.send(Action::Tick)?,
             Event::Render => action_tx.send(Action::Render)?,
             Event::Resize(x, y) => action_tx.send(Action::Resize(x, y))?,
+            // The help overlay is scrolled by the scrolling keys and closed by any other key press
+            // or click, none of which are passed on.
+            Event::Key(_) | Event::Mouse(_) if self.help_visible => {
+                if self.help_scroll_view.handle_event(&event)?.absorb {
+                    action_tx.send(Action::Render)?;
+                } else if matches!(
+                    event,
+                    Event::Key(KeyEvent {
+                        kind: KeyEventKind::Press,
+                        ..
+                    }) | Event::Mouse(MouseEvent {
+                        kind: MouseEventKind::Down(_),
+                        ..
+                    })
+                ) {
+                    self.help_visible = false;
+                    action_tx.send(Action::Render)?;
+                }
+
+                return Ok(());
+            }
             Event::Key(key) => self.handle_key_event(key)?,
+            Event::Mouse(mouse) => return self.handle_mouse_event(mouse),
             _ => {}
         }
 
-        self.focus_path
-            .for_each_component_mut::<Result<()>>(
-                &mut *self.root_component,
-                &mut |_| ControlFlow::Continue(()),
-                &mut |focused_component| -> ControlFlow<Result<()>, ()> {
-                    match focused_component.handle_event(&event) {
-                        Ok(HandleEventSuccess { action, absorb }) => {
-                            if let Some(action) = action {
-                                action_tx.send(action).unwrap();
-                            }
+        self.dispatch_event(&self.focus_path.clone(), &event)
+    }
 
-                            if absorb {
-                                ControlFlow::Break(Ok(()))
-                            } else {
-                                ControlFlow::Continue(())
-                            }
+    /// Passes the event to the component at the end of `path` and then to its ancestors, until
+    /// one of them absorbs it.
+    fn dispatch_event(&mut self, path: &ComponentIdPath, event: &Event) -> Result<()> {
+        let action_tx = self.action_tx.clone();
+
+        path.for_each_component_mut::<Result<()>>(
+            &mut *self.root_component,
+            &mut |_| ControlFlow::Continue(()),
+            &mut |component| -> ControlFlow<Result<()>, ()> {
+                let result = if component.is_enabled() {
+                    component.handle_event(event)
+                } else {
+                    component.handle_event_disabled(event)
+                };
+
+                match result {
+                    Ok(HandleEventSuccess { action, absorb }) => {
+                        if let Some(action) = action {
+                            action_tx.send(action).unwrap();
+                        }
+
+                        if absorb {
+                            ControlFlow::Break(Ok(()))
+                        } else {
+                            ControlFlow::Continue(())
                         }
-                        Err(error) => ControlFlow::Break(Err(error)),
                     }
-                },
-            )
-            .break_value()
-            .transpose()?;
-
-        // let (focused_component, _) = self
-        //     .focus_path
-        //     .find_deepest_available_component_mut(&mut *self.root_component);
-        // focused_component.handle_event(event)?;
-        // components::depth_first_search_mut(
-        //     &mut *self.root_component,
-        //     &mut |component| {
-        //         if let Some(action) = component.handle_event(event.clone()).unwrap() {
-        //             action_tx.send(action).unwrap();
-        //         }
-
-        //         ControlFlow::Continue(())
-        //     },
-        //     &mut |_| ControlFlow::Continue(()),
-        // );
-        // for component in self.components.iter_mut() {
-        //     if let Some(action) = component.handle_event(event.clone())? {
-        //         action_tx.send(action)?;
-        //     }
-        // }
+                    Err(error) => ControlFlow::Break(Err(error)),
+                }
+            },
+        )
+        .break_value()
+        .transpose()?;
+
         Ok(())
     }
 
+    /// Passes the mouse event to the innermost component drawn under the pointer.
+    /// A left click first moves the focus to the innermost focusable component under the pointer.
+    #[instrument(skip(self))]
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Result<()> {
+        let position = Position::new(mouse.column, mouse.row);
+        // Children are drawn after their parents, so the innermost component is found first.
+        // Components not part of the tree, such as those internal to other components, are skipped.
+        let Some(path) = self
+            .drawn_areas
+            .iter()
+            .rev()
+            .filter(|(_, area)| area.contains(position))
+            .find_map(|(id, _)| {
+                find_component_by_id_mut(&mut *self.root_component, *id).map(|(_, path)| path)
+            })
+        else {
+            return Ok(());
+        };
+
+        if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+            let focusable_id = (1..=path.len()).rev().find_map(|length| {
+                let ancestor_path = ComponentIdPath(path[..length].to_vec());
+                let (component, _) =
+                    ancestor_path.find_deepest_available_component(&*self.root_component);
+
+                component::accepts_focus(component).then(|| component.get_id())
+            });
+
+            if let Some(focusable_id) = focusable_id {
+                self.focus_component(focusable_id)?;
+            }
+        }
+
+        self.dispatch_event(&path, &Event::Mouse(mouse))
+    }
+
+    /// Triggers the action bound to the sequence of the pending keys followed by `key`, if any.
     #[instrument(skip(self))]
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
         tracing::trace!(?key);
-        let action = match key {
-            KeyEvent {
-                code: KeyCode::Char('c' | 'd'),
-                modifiers: KeyModifiers::CONTROL,
-                kind: KeyEventKind::Press,
-                ..
-            } => Some(Action::Quit),
-            KeyEvent {
-                code: code @ (KeyCode::Tab | KeyCode::BackTab),
-                modifiers: modifiers @ (KeyModifiers::NONE | KeyModifiers::SHIFT),
-                kind: KeyEventKind::Press | KeyEventKind::Repeat,
-                ..
-            } => Some(Action::FocusChange(FocusChange {
-                direction: if (modifiers != KeyModifiers::NONE) || (code == KeyCode::BackTab) {
-                    FocusChangeDirection::Backward
-                } else {
-                    FocusChangeDirection::Forward
-                },
-                scope: FocusChangeScope::HorizontalAndVertical,
-            })),
-            KeyEvent {
-                code: code @ (KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right),
-                modifiers: KeyModifiers::ALT,
-                kind: KeyEventKind::Press | KeyEventKind::Repeat,
-                ..
-            } => Some(Action::FocusChange(FocusChange {
-                direction: if code == KeyCode::Down || code == KeyCode::Right {
-                    FocusChangeDirection::Forward
-                } else {
-                    FocusChangeDirection::Backward
-                },
-                scope: if code == KeyCode::Up || code == KeyCode::Down {
-                    FocusChangeScope::Vertical
-                } else {
-                    FocusChangeScope::Horizontal
-                },
-            })),
-            _ => None,
-        };
-        if let Some(action) = action {
-            self.action_tx.send(action)?;
+
+        if !matches!(key.kind, KeyEventKind::Press | KeyEventKind::Repeat) {
+            return Ok(());
         }
+
+        let key_event = normalize_key_event(key.code, key.modifiers);
+
+        self.clear_pending_key_events_if_expired();
+        self.pending_key_events.push(key_event);
+        self.pending_key_events_instant = Some(Instant::now());
+
+        // A key that does not continue the pending sequence may still start a new one.
+        if self
+            .keybindings
+            .get_action(self.mode, &self.pending_key_events)
+            .is_none()
+            && !self
+                .keybindings
+                .is_prefix(self.mode, &self.pending_key_events)
+        {
+            self.pending_key_events = vec![key_event];
+        }
+
+        if let Some(action) = self
+            .keybindings
+            .get_action(self.mode, &self.pending_key_events)
+        {
+            self.action_tx.send(action.clone())?;
+            self.clear_pending_key_events();
+        } else if !self
+            .keybindings
+            .is_prefix(self.mode, &self.pending_key_events)
+        {
+            self.clear_pending_key_events();
+        }
+
         Ok(())
     }
 
+    fn clear_pending_key_events(&mut self) {
+        self.pending_key_events.clear();
+        self.pending_key_events_instant = None;
+    }
+
+    fn clear_pending_key_events_if_expired(&mut self) {
+        if let Some(instant) = self.pending_key_events_instant
+            && instant.elapsed() > self.key_sequence_timeout
+        {
+            self.clear_pending_key_events();
+        }
+    }
+
     #[instrument(skip(self))]
     fn change_focus(&mut self, focus_change: FocusChange) -> Result<()> {
         match focus_change.scope {
@@ -224,7 +323,15 @@ impl App {
                 let _ = component::depth_first_search(
                     &*self.root_component,
                     &mut |component| -> ControlFlow<()> {
-                        if component.is_focusable() {
+                        // The focused component may be a container, which is not focusable
+                        // itself, in which case its first focusable descendant is next.
+                        let focused = component.get_id() == deepest_available_id;
+
+                        if focused {
+                            previous_focusable_component = last_focusable_component;
+                        }
+
+                        if component::accepts_focus(component) {
                             if first_focusable_component.is_none() {
                                 first_focusable_component = Some(component);
                             }
@@ -233,14 +340,13 @@ impl App {
                                 next_focusable_component = Some(component);
                             }
 
-                            if component.get_id() == deepest_available_id {
-                                focused_component_visited = true;
-                                previous_focusable_component = last_focusable_component;
-                            }
-
                             last_focusable_component = Some(component);
                         }
 
+                        if focused {
+                            focused_component_visited = true;
+                        }
+
                         ControlFlow::Continue(())
                     },
                     &mut |_component| -> ControlFlow<()> { ControlFlow::Continue(()) },
@@ -259,23 +365,108 @@ impl App {
 
                 if let Some(next_focusable_component) = next_focusable_component {
                     let next_focusable_component_id = next_focusable_component.get_id();
-                    let (newly_selected_component, focus_path) = find_component_by_id_mut(
-                        &mut *self.root_component,
-                        next_focusable_component_id,
-                    )
-                    .unwrap();
-                    self.focus_path = focus_path;
-                    newly_selected_component.handle_event(&Event::FocusGained)?;
-                    tracing::debug!(focus_path=?self.focus_path, "Focus changed.");
+                    self.set_focus(next_focusable_component_id)?;
+                }
+            }
+            FocusChangeScope::Horizontal => {
+                if let Some(id) = self.get_sibling_to_focus(focus_change.direction) {
+                    self.focus_component(id)?;
+                }
+            }
+            FocusChangeScope::Vertical => {
+                if let Some(id) = self.get_parent_or_child_to_focus(focus_change.direction) {
+                    self.focus_component(id)?;
                 }
             }
-            FocusChangeScope::Horizontal => unimplemented!(),
-            FocusChangeScope::Vertical => unimplemented!(),
         }
 
         Ok(())
     }
 
+    /// Finds the sibling following or preceding the focused component, wrapping around.
+    /// Siblings without anything focusable within them are skipped. If the focused component has
+    /// no such siblings, the siblings of its closest ancestor that does are considered instead.
+    fn get_sibling_to_focus(&self, direction: FocusChangeDirection) -> Option<ComponentId> {
+        let (_, focused_path) = self
+            .focus_path
+            .find_deepest_available_component(&*self.root_component);
+
+        for depth in (1..=focused_path.len()).rev() {
+            let id = focused_path[depth - 1];
+            let (parent, _) = ComponentIdPath(focused_path[..depth - 1].into())
+                .find_deepest_available_component(&*self.root_component);
+            let siblings = parent
+                .get_children()
+                .into_iter()
+                .filter(|sibling| sibling.get_id() == id || component::contains_focusable(*sibling))
+                .map(|sibling| sibling.get_id())
+                .collect::<Vec<_>>();
+
+            if siblings.len() < 2 {
+                continue;
+            }
+
+            let index = siblings.iter().position(|sibling| *sibling == id)?;
+            let index = match direction {
+                FocusChangeDirection::Forward => (index + 1) % siblings.len(),
+                FocusChangeDirection::Backward => (index + siblings.len() - 1) % siblings.len(),
+            };
+
+            return Some(siblings[index]);
+        }
+
+        None
+    }
+
+    /// Finds the parent of the focused component when moving backward, or its first child with
+    /// anything focusable within it when moving forward.
+    fn get_parent_or_child_to_focus(&self, direction: FocusChangeDirection) -> Option<ComponentId> {
+        let (focused_component, focused_path) = self
+            .focus_path
+            .find_deepest_available_component(&*self.root_component);
+
+        match direction {
+            // The root component is never focused by itself.
+            FocusChangeDirection::Backward => focused_path
+                .len()
+                .checked_sub(2)
+                .map(|parent_index| focused_path[parent_index]),
+            FocusChangeDirection::Forward => focused_component
+                .get_children()
+                .into_iter()
+                .find(|child| component::contains_focusable(*child))
+                .map(|child| child.get_id()),
+        }
+    }
+
+    /// Moves the focus to the component with the given `id`, if it exists.
+    fn set_focus(&mut self, id: ComponentId) -> Result<()> {
+        let Some((newly_selected_component, focus_path)) =
+            find_component_by_id_mut(&mut *self.root_component, id)
+        else {
+            return Ok(());
+        };
+
+        self.focus_path = focus_path;
+        newly_selected_component.handle_event(&Event::FocusGained)?;
+        tracing::debug!(focus_path=?self.focus_path, "Focus changed.");
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn focus_component(&mut self, id: ComponentId) -> Result<()> {
+        let (originally_selected_component, _) = self
+            .focus_path
+            .find_deepest_available_component_mut(&mut *self.root_component);
+
+        if originally_selected_component.get_id() == id {
+            return Ok(());
+        }
+
+        originally_selected_component.handle_event(&Event::FocusLost)?;
+        self.set_focus(id)
+    }
+
     #[instrument(skip(self, tui))]
     fn handle_actions(&mut self, tui: &mut Tui) -> Result<()> {
         while let Ok(action) = self.action_rx.try_recv() {
@@ -283,7 +474,8 @@ impl App {
 
             match action {
                 Action::Tick => {
-                    self.last_tick_key_events.drain(..);
+                    self.clear_pending_key_events_if_expired();
+                    self.clear_clipboard_if_expired(tui)?;
                     component_message = Some(ComponentMessage::OnTick);
                 }
                 Action::BroadcastMessage(message) => component_message = Some(message),
@@ -294,6 +486,26 @@ impl App {
                 Action::Resize(w, h) => self.handle_resize(tui, w, h)?,
                 Action::Render => self.render(tui)?,
                 Action::FocusChange(focus_change) => self.change_focus(focus_change)?,
+                Action::FocusComponent(id) => self.focus_component(id)?,
+                Action::NavigateBack => {
+                    component_message = Some(ComponentMessage::Navigate(Navigation::Back))
+                }
+                Action::NavigateForward => {
+                    component_message = Some(ComponentMessage::Navigate(Navigation::Forward))
+                }
+                Action::NavigateHome => {
+                    component_message = Some(ComponentMessage::Navigate(Navigation::Home))
+                }
+                Action::ToggleBreadcrumbMask => {
+                    component_message = Some(ComponentMessage::ToggleBreadcrumbMask)
+                }
+                Action::CopyToClipboard(content) => self.copy_to_clipboard(tui, &content)?,
+                Action::ApplyConfig(config) => self.apply_config(tui, config)?,
+                Action::ToggleHelp => {
+                    self.help_visible = !self.help_visible;
+                    self.help_scroll_view.scroll_to_top();
+                    self.action_tx.send(Action::Render)?;
+                }
             }
 
             if let Some(component_message) = component_message {
@@ -313,6 +525,71 @@ impl App {
         Ok(())
     }
 
+    /// Applies the configuration values that may change while running, then passes the
+    /// configuration on to the components.
+    #[instrument(skip(self, tui))]
+    fn apply_config(&mut self, tui: &mut Tui, config: Arc<Config>) -> Result<()> {
+        if config.tick_rate != self.tick_rate || config.frame_rate != self.frame_rate {
+            self.tick_rate = config.tick_rate;
+            self.frame_rate = config.frame_rate;
+            tui.tick_rate = config.tick_rate;
+            tui.frame_rate = config.frame_rate;
+            // Restart the event loop with the new rates.
+            tui.start();
+        }
+
+        tui.set_mouse(config.ui.mouse)?;
+        self.mouse = config.ui.mouse;
+        self.clipboard_clear_timeout = Self::get_clipboard_clear_timeout(&config);
+        self.action_tx
+            .send(Action::BroadcastMessage(ComponentMessage::ConfigChange(
+                config,
+            )))?;
+        Ok(())
+    }
+
+    fn get_clipboard_clear_timeout(config: &Config) -> Option<Duration> {
+        (config.ui.clipboard_clear_timeout > 0.0)
+            .then(|| Duration::from_secs_f64(config.ui.clipboard_clear_timeout))
+    }
+
+    fn write_clipboard(tui: &mut Tui, content: &str) -> Result<()> {
+        crossterm::execute!(
+            tui.terminal.backend_mut(),
+            CopyToClipboard::to_clipboard_from(content)
+        )?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, tui, content))]
+    fn copy_to_clipboard(&mut self, tui: &mut Tui, content: &str) -> Result<()> {
+        Self::write_clipboard(tui, content)?;
+        self.clipboard_clear_instant = self
+            .clipboard_clear_timeout
+            .map(|timeout| Instant::now() + timeout);
+        self.action_tx
+            .send(Action::BroadcastMessage(ComponentMessage::ClipboardCopy {
+                clear_instant: self.clipboard_clear_instant,
+            }))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, tui))]
+    fn clear_clipboard_if_expired(&mut self, tui: &mut Tui) -> Result<()> {
+        if let Some(clear_instant) = self.clipboard_clear_instant
+            && Instant::now() >= clear_instant
+        {
+            // Most terminals clear the clipboard when given an empty selection, but some ignore it
+            // or treat it as a query, so there is no guarantee the clipboard was cleared.
+            Self::write_clipboard(tui, "")?;
+            self.clipboard_clear_instant = None;
+            self.action_tx
+                .send(Action::BroadcastMessage(ComponentMessage::ClipboardClear))?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip(self, tui))]
     fn handle_resize(&mut self, tui: &mut Tui, w: u16, h: u16) -> Result<()> {
         tui.resize(Rect::new(0, 0, w, h))?;
@@ -326,14 +603,59 @@ impl App {
         tui.draw(|frame| {
             let area = frame.area();
             let (now, elapsed_time) = self.get_elapsed_time();
-            result = self.root_component.default_draw(
-                &mut DrawContext::new(frame, self.get_focused_component_id(), now, elapsed_time),
-                area,
-            );
+            let focused_id = self.get_focused_component_id();
+
+            self.drawn_areas.clear();
+            let help_sections = self.help_visible.then(|| self.get_help_sections());
+            let mut context =
+                DrawContext::new(frame, focused_id, now, elapsed_time, &mut self.drawn_areas);
+
+            result = self.root_component.default_draw(&mut context, area);
+
+            if let Some(sections) = help_sections
+                && result.is_ok()
+            {
+                result = HelpOverlay {
+                    sections,
+                    scroll_view: &self.help_scroll_view,
+                }
+                .draw(&mut context, area);
+            }
         })?;
         result
     }
 
+    /// The key hints of the focused component and its ancestors, followed by the keybindings of
+    /// the current mode.
+    fn get_help_sections(&self) -> Vec<KeyHints> {
+        let mut sections = (0..=self.focus_path.len())
+            .rev()
+            .filter_map(|length| {
+                let path = ComponentIdPath(self.focus_path[..length].to_vec());
+                let (component, _) = path.find_deepest_available_component(&*self.root_component);
+
+                component.get_key_hints()
+            })
+            .collect::<Vec<_>>();
+        let mut keybindings = KeyHints::new("Global");
+
+        if let Some(bindings) = self.keybindings.get(&self.mode) {
+            // List each action once, with all the keys bound to it.
+            let actions = bindings
+                .iter()
+                .map(|(sequence, action)| (action.get_description(), format_key_sequence(sequence)))
+                .into_group_map();
+
+            for (description, mut sequences) in actions.into_iter().sorted() {
+                sequences.sort();
+                keybindings = keybindings.with_hint(sequences.join(" "), description);
+            }
+        }
+
+        sections.push(keybindings);
+        sections
+    }
+
     fn get_elapsed_time(&mut self) -> (Instant, Duration) {
         let current_instant = Instant::now();
         if let Some(first_render_instant) = self.first_render_instant.as_ref() {
@@ -5,18 +5,34 @@ use clap::Parser;
 #[derive(Parser, Debug, Clone)]
 #[command(author, version = VERSION_MESSAGE, about)]
 pub struct Args {
+    /// A configuration file to load, overriding the user configuration.
+    #[arg(short, long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
     /// Tick rate, i.e. number of ticks per second
-    #[arg(short, long, value_name = "FLOAT", default_value_t = 4.0)]
-    pub tick_rate: f64,
+    #[arg(short, long, value_name = "FLOAT")]
+    pub tick_rate: Option<f64>,
 
     /// Frame rate, i.e. number of frames per second
-    #[arg(short, long, value_name = "FLOAT", default_value_t = 60.0)]
-    pub frame_rate: f64,
+    #[arg(short, long, value_name = "FLOAT")]
+    pub frame_rate: Option<f64>,
 
     /// The path to a registry directory containing a `registry.cbor` file.
     /// By default, the current working directory is used.
-    #[arg(short('d'), long, default_value = ".")]
-    pub registry_directory: PathBuf,
+    #[arg(short('d'), long)]
+    pub registry_directory: Option<PathBuf>,
+
+    /// Open the registry for writing, allowing new records and record versions to be created.
+    /// Prevents other processes from accessing the registry in the meantime.
+    #[arg(long)]
+    pub write: bool,
+
+    /// The number of seconds after which text copied to the clipboard is overwritten.
+    /// Zero disables clearing the clipboard.
+    /// Clearing is best effort, as some terminals ignore the empty OSC 52 selection it is done
+    /// with, or treat it as a query.
+    #[arg(long, value_name = "SECONDS")]
+    pub clipboard_clear_timeout: Option<f64>,
 
     /// Enforce a maximum width of the user interface.
     #[arg(short('w'), long)]
@@ -25,6 +41,15 @@ pub struct Args {
     /// Enforce a maximum height of the user interface.
     #[arg(short('h'), long)]
     pub force_max_height: Option<u16>,
+
+    /// The number of consecutive missing versions to look past when listing the versions of a
+    /// record.
+    #[arg(long, value_name = "COUNT")]
+    pub max_version_lookahead: Option<u64>,
+
+    /// The number of attempts to resolve colliding fragment file names.
+    #[arg(long, value_name = "COUNT")]
+    pub max_collision_resolution_attempts: Option<u64>,
 }
 
 pub const VERSION_MESSAGE: &str = concat!(
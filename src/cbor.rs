@@ -1,16 +1,25 @@
 use std::borrow::Cow;
 
+use base64::Engine;
 use itertools::Itertools;
 use ratatui::{
     style::{Color, Style},
     text::{Line, Span},
-    widgets::Row,
 };
 use rrr::{
     cbor::{self, ValueExt},
-    record::RecordMetadataKey,
+    record::{RecordMetadata, RecordMetadataKey},
 };
 
+/// Tags with well-known meanings, as registered in the IANA CBOR Tags registry.
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_EXPECTED_BASE64URL: u64 = 21;
+const TAG_EXPECTED_BASE64: u64 = 22;
+const TAG_EXPECTED_BASE16: u64 = 23;
+const TAG_ENCODED_CBOR: u64 = 24;
+const TAG_URI: u64 = 32;
+
 fn styled<'a>(string: impl Into<Cow<'a, str>>) -> Span<'a> {
     Span::styled(string, Style::new().fg(Color::Rgb(0x3F, 0x3F, 0x3F)))
 }
@@ -21,17 +30,260 @@ fn line<'a>(ty: impl AsRef<str>, content: impl Into<Line<'a>>) -> Line<'a> {
     l
 }
 
-pub fn record_metadata_to_row<'a>(key: RecordMetadataKey<'a>, value: &'a cbor::Value) -> Row<'a> {
-    match key {
-        RecordMetadataKey::Id(id) => {
-            Row::new([Line::raw(id.to_string()), cbor_value_to_line(value)])
+/// Parses the data as a single CBOR item, failing if there are any trailing bytes.
+pub fn parse_cbor(data: &[u8]) -> Option<cbor::Value> {
+    let mut cursor = std::io::Cursor::new(data);
+    let value = ciborium::from_reader::<cbor::Value, _>(&mut cursor).ok()?;
+
+    (cursor.position() as usize == data.len()).then_some(value)
+}
+
+/// Parses the CBOR item embedded within a value tagged as encoded CBOR.
+pub fn decode_embedded_cbor(value: &cbor::Value) -> Option<cbor::Value> {
+    match value {
+        cbor::Value::Tag(TAG_ENCODED_CBOR, inner) => parse_cbor(inner.as_bytes()?),
+        _ => None,
+    }
+}
+
+/// Encodes the value and formats the encoding in hexadecimal.
+fn cbor_value_to_raw_hex(value: &cbor::Value) -> String {
+    let mut encoded = Vec::new();
+
+    match ciborium::into_writer(value, &mut encoded) {
+        Ok(()) => format!("{:02x}", encoded.iter().format("")),
+        Err(_) => format!("{value:?}"),
+    }
+}
+
+/// Formats a big-endian unsigned integer of any length in decimal.
+fn format_big_unsigned(mut bytes: Vec<u8>) -> String {
+    let mut decimal_digits = Vec::new();
+
+    while bytes.iter().any(|byte| *byte != 0) {
+        let mut remainder = 0_u16;
+
+        for byte in &mut bytes {
+            let accumulator = (remainder << 8) | *byte as u16;
+            *byte = (accumulator / 10) as u8;
+            remainder = accumulator % 10;
+        }
+
+        decimal_digits.push(char::from(b'0' + remainder as u8));
+    }
+
+    if decimal_digits.is_empty() {
+        "0".into()
+    } else {
+        decimal_digits.into_iter().rev().collect()
+    }
+}
+
+/// Formats the magnitude of a bignum, negated if `negative`, in decimal.
+fn format_bignum(magnitude: &[u8], negative: bool) -> String {
+    if !negative {
+        return format_big_unsigned(magnitude.to_vec());
+    }
+
+    // A negative bignum represents the value `-1 - magnitude`.
+    let mut incremented = [&[0][..], magnitude].concat();
+
+    for byte in incremented.iter_mut().rev() {
+        let (sum, overflow) = byte.overflowing_add(1);
+        *byte = sum;
+
+        if !overflow {
+            break;
+        }
+    }
+
+    format!("-{}", format_big_unsigned(incremented))
+}
+
+fn line_into_owned(line: Line<'_>) -> Line<'static> {
+    Line {
+        spans: line
+            .spans
+            .into_iter()
+            .map(|span| Span::styled(span.content.into_owned(), span.style))
+            .collect(),
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+/// Formats the value in the CBOR diagnostic notation (RFC 8949, Section 8), with nested arrays
+/// and maps indented on separate lines.
+pub fn cbor_value_to_diagnostic(value: &cbor::Value) -> String {
+    fn write_diagnostic(output: &mut String, value: &cbor::Value, indent: usize) {
+        const INDENT: &str = "  ";
+
+        match value {
+            cbor::Value::Integer(integer) => output.push_str(&i128::from(*integer).to_string()),
+            cbor::Value::Bytes(bytes) => {
+                output.push_str(&format!("h'{:02x}'", bytes.iter().format("")))
+            }
+            cbor::Value::Float(float) => output.push_str(&match float {
+                float if float.is_nan() => "NaN".to_string(),
+                float if float.is_infinite() && *float > 0.0 => "Infinity".to_string(),
+                float if float.is_infinite() => "-Infinity".to_string(),
+                float => format!("{float:?}"),
+            }),
+            cbor::Value::Text(text) => output.push_str(&format!("{text:?}")),
+            cbor::Value::Bool(boolean) => output.push_str(&boolean.to_string()),
+            cbor::Value::Null => output.push_str("null"),
+            cbor::Value::Tag(TAG_ENCODED_CBOR, _)
+                if let Some(embedded) = decode_embedded_cbor(value) =>
+            {
+                output.push_str(&format!("{TAG_ENCODED_CBOR}(<< "));
+                write_diagnostic(output, &embedded, indent);
+                output.push_str(" >>)");
+            }
+            cbor::Value::Tag(tag, inner) => {
+                output.push_str(&format!("{tag}("));
+                write_diagnostic(output, inner, indent);
+                output.push(')');
+            }
+            cbor::Value::Array(array) if array.is_empty() => output.push_str("[]"),
+            cbor::Value::Array(array) => {
+                output.push_str("[\n");
+                for (index, item) in array.iter().enumerate() {
+                    output.push_str(&INDENT.repeat(indent + 1));
+                    write_diagnostic(output, item, indent + 1);
+                    output.push_str(if index + 1 < array.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&INDENT.repeat(indent));
+                output.push(']');
+            }
+            cbor::Value::Map(map) if map.is_empty() => output.push_str("{}"),
+            cbor::Value::Map(map) => {
+                output.push_str("{\n");
+                for (index, (key, value)) in map.iter().enumerate() {
+                    output.push_str(&INDENT.repeat(indent + 1));
+                    write_diagnostic(output, key, indent + 1);
+                    output.push_str(": ");
+                    write_diagnostic(output, value, indent + 1);
+                    output.push_str(if index + 1 < map.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&INDENT.repeat(indent));
+                output.push('}');
+            }
+            // Values of kinds added in the future are shown as their raw encoding.
+            other => output.push_str(&format!("/unknown/ h'{}'", cbor_value_to_raw_hex(other))),
+        }
+    }
+
+    let mut output = String::new();
+    write_diagnostic(&mut output, value, 0);
+    output
+}
+
+/// Converts the metadata into a single CBOR map, with well-known keys named by text.
+pub fn record_metadata_to_cbor_value(metadata: &RecordMetadata) -> cbor::Value {
+    cbor::Value::Map(
+        metadata
+            .iter_with_semantic_keys()
+            .map(|(key, value)| {
+                let key = match key {
+                    RecordMetadataKey::Id(id) => cbor::Value::Text(id.to_string()),
+                    RecordMetadataKey::Custom(key) => key.0.clone(),
+                };
+
+                (key, value.clone())
+            })
+            .collect(),
+    )
+}
+
+/// Converts the value into JSON. Byte strings become hexadecimal strings, tags are dropped,
+/// and map keys that are not text are formatted in the CBOR diagnostic notation.
+pub fn cbor_value_to_json(value: &cbor::Value) -> serde_json::Value {
+    match value {
+        cbor::Value::Integer(integer) => {
+            let integer = i128::from(*integer);
+
+            if let Ok(integer) = i64::try_from(integer) {
+                integer.into()
+            } else if let Ok(integer) = u64::try_from(integer) {
+                integer.into()
+            } else {
+                integer.to_string().into()
+            }
+        }
+        cbor::Value::Bytes(bytes) => format!("{:02x}", bytes.iter().format("")).into(),
+        cbor::Value::Float(float) => serde_json::Number::from_f64(*float)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        cbor::Value::Text(text) => text.as_str().into(),
+        cbor::Value::Bool(boolean) => (*boolean).into(),
+        cbor::Value::Null => serde_json::Value::Null,
+        cbor::Value::Tag(_, inner) => cbor_value_to_json(inner),
+        cbor::Value::Array(array) => array.iter().map(cbor_value_to_json).collect(),
+        cbor::Value::Map(map) => map
+            .iter()
+            .map(|(key, value)| {
+                let key = match key {
+                    cbor::Value::Text(text) => text.clone(),
+                    key => cbor_value_to_diagnostic(key),
+                };
+
+                (key, cbor_value_to_json(value))
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        other => cbor_value_to_raw_hex(other).into(),
+    }
+}
+
+/// Converts JSON into a CBOR value. Numbers become integers where possible.
+pub fn json_to_cbor_value(json: &serde_json::Value) -> cbor::Value {
+    match json {
+        serde_json::Value::Null => cbor::Value::Null,
+        serde_json::Value::Bool(boolean) => cbor::Value::Bool(*boolean),
+        serde_json::Value::Number(number) => {
+            if let Some(integer) = number.as_i64() {
+                cbor::Value::Integer(integer.into())
+            } else if let Some(integer) = number.as_u64() {
+                cbor::Value::Integer(integer.into())
+            } else {
+                cbor::Value::Float(number.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        serde_json::Value::String(text) => cbor::Value::Text(text.clone()),
+        serde_json::Value::Array(array) => {
+            cbor::Value::Array(array.iter().map(json_to_cbor_value).collect())
         }
-        RecordMetadataKey::Custom(key) => {
-            Row::new([cbor_value_to_line(key.0), cbor_value_to_line(value)])
+        serde_json::Value::Object(object) => cbor::Value::Map(
+            object
+                .iter()
+                .map(|(key, value)| (cbor::Value::Text(key.clone()), json_to_cbor_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Returns `true` iff both keys refer to the same metadata entry.
+pub fn record_metadata_keys_eq(lhs: &RecordMetadataKey, rhs: &RecordMetadataKey) -> bool {
+    match (lhs, rhs) {
+        (RecordMetadataKey::Id(lhs), RecordMetadataKey::Id(rhs)) => {
+            lhs.to_string() == rhs.to_string()
         }
+        (RecordMetadataKey::Custom(lhs), RecordMetadataKey::Custom(rhs)) => lhs.0 == rhs.0,
+        _ => false,
     }
 }
 
+/// Formats a metadata entry as `key: value` on a single line.
+pub fn record_metadata_to_line<'a>(key: RecordMetadataKey<'a>, value: &'a cbor::Value) -> Line<'a> {
+    let mut line = match key {
+        RecordMetadataKey::Id(id) => Line::raw(id.to_string()),
+        RecordMetadataKey::Custom(key) => cbor_value_to_line(key.0),
+    };
+    line.push_span(styled(": "));
+    line.extend(cbor_value_to_line(value).spans);
+    line
+}
+
 pub fn cbor_value_to_line(value: &cbor::Value) -> Line {
     if let Some(integer) = value.as_integer() {
         return line("integer", i128::from(integer).to_string());
@@ -100,8 +352,134 @@ pub fn cbor_value_to_line(value: &cbor::Value) -> Line {
     }
 
     if let Some((tag, inner)) = value.as_tag() {
-        return line(format!("tag({tag}) "), cbor_value_to_line(inner));
+        return tagged_cbor_value_to_line(tag, inner);
+    }
+
+    // Values of kinds added in the future are shown as their raw encoding.
+    line("unknown", cbor_value_to_raw_hex(value))
+}
+
+fn tagged_cbor_value_to_line(tag: u64, inner: &cbor::Value) -> Line<'_> {
+    match (tag, inner) {
+        (TAG_ENCODED_CBOR, cbor::Value::Bytes(bytes)) => match parse_cbor(bytes) {
+            Some(embedded) => line("cbor", line_into_owned(cbor_value_to_line(&embedded))),
+            None => line("cbor (invalid)", format!("{:02x}", bytes.iter().format(""))),
+        },
+        (TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM, cbor::Value::Bytes(bytes)) => {
+            line("bignum", format_bignum(bytes, tag == TAG_NEGATIVE_BIGNUM))
+        }
+        (TAG_URI, cbor::Value::Text(uri)) => line("uri", uri.as_str()),
+        (TAG_EXPECTED_BASE64URL, cbor::Value::Bytes(bytes)) => line(
+            "base64url",
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+        ),
+        (TAG_EXPECTED_BASE64, cbor::Value::Bytes(bytes)) => line(
+            "base64",
+            base64::engine::general_purpose::STANDARD.encode(bytes),
+        ),
+        (TAG_EXPECTED_BASE16, cbor::Value::Bytes(bytes)) => {
+            line("base16", format!("{:02x}", bytes.iter().format("")))
+        }
+        (tag, inner) => line(format!("tag({tag})"), cbor_value_to_line(inner)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn bytes(bytes: &[u8]) -> cbor::Value {
+        cbor::Value::Bytes(bytes.to_vec())
+    }
+
+    fn tagged(tag: u64, inner: cbor::Value) -> cbor::Value {
+        cbor::Value::Tag(tag, Box::new(inner))
+    }
+
+    #[test]
+    fn parse_cbor_trailing_bytes() {
+        assert_eq!(parse_cbor(&[0x01]), Some(cbor::Value::Integer(1.into())));
+        assert_eq!(parse_cbor(&[0x01, 0x01]), None);
+        assert_eq!(parse_cbor(&[]), None);
     }
 
-    panic!("Unrecognized type of CBOR value: {value:?}");
+    #[test]
+    fn format_bignum_positive() {
+        assert_eq!(format_bignum(&[], false), "0");
+        assert_eq!(format_bignum(&[0x01, 0x00], false), "256");
+        assert_eq!(
+            format_bignum(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0], false),
+            "18446744073709551616"
+        );
+    }
+
+    #[test]
+    fn format_bignum_negative() {
+        assert_eq!(format_bignum(&[], true), "-1");
+        assert_eq!(format_bignum(&[0x00], true), "-1");
+        assert_eq!(format_bignum(&[0xff], true), "-256");
+        assert_eq!(
+            format_bignum(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff], true),
+            "-18446744073709551616"
+        );
+    }
+
+    #[test]
+    fn cbor_value_to_line_bignum() {
+        assert_eq!(
+            cbor_value_to_line(&tagged(TAG_POSITIVE_BIGNUM, bytes(&[0x01, 0x00]))).to_string(),
+            "bignum 256"
+        );
+        assert_eq!(
+            cbor_value_to_line(&tagged(TAG_NEGATIVE_BIGNUM, bytes(&[0x01, 0x00]))).to_string(),
+            "bignum -257"
+        );
+        // Bignums must be byte strings, anything else is shown as a plain tag.
+        assert_eq!(
+            cbor_value_to_line(&tagged(TAG_POSITIVE_BIGNUM, cbor::Value::Text("1".into())))
+                .to_string(),
+            "tag(2) text 1"
+        );
+    }
+
+    #[test]
+    fn cbor_value_to_line_embedded_cbor() {
+        assert_eq!(
+            cbor_value_to_line(&tagged(TAG_ENCODED_CBOR, bytes(&[0x63, b'a', b'b', b'c'])))
+                .to_string(),
+            "cbor text abc"
+        );
+        assert_eq!(
+            cbor_value_to_line(&tagged(TAG_ENCODED_CBOR, bytes(&[0x01, 0x01]))).to_string(),
+            "cbor (invalid) 0101"
+        );
+    }
+
+    #[test]
+    fn cbor_value_to_diagnostic_embedded_cbor() {
+        assert_eq!(
+            cbor_value_to_diagnostic(&tagged(TAG_ENCODED_CBOR, bytes(&[0x82, 0x01, 0x02]))),
+            "24(<< [\n  1,\n  2\n] >>)"
+        );
+        assert_eq!(
+            cbor_value_to_diagnostic(&tagged(TAG_ENCODED_CBOR, bytes(&[0xff]))),
+            "24(h'ff')"
+        );
+    }
+
+    #[test]
+    fn cbor_value_to_raw_hex_encodes() {
+        // The fallback for values of unknown kinds shows their encoding.
+        assert_eq!(cbor_value_to_raw_hex(&cbor::Value::Integer(1.into())), "01");
+        assert_eq!(
+            cbor_value_to_raw_hex(&cbor::Value::Text("a".into())),
+            "6161"
+        );
+        assert_eq!(
+            cbor_value_to_raw_hex(&tagged(TAG_POSITIVE_BIGNUM, bytes(&[0x01]))),
+            "c24101"
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     cell::RefCell,
     fmt::Debug,
     ops::ControlFlow,
@@ -6,10 +7,11 @@ use std::{
 };
 
 use color_eyre::Result;
-use ratatui::{layout::Rect, Frame};
+use ratatui::{layout::Rect, style::Style, Frame};
 
 use crate::{
     action::{Action, ComponentMessage},
+    color::ColorOklch,
     tui::Event,
 };
 
@@ -53,7 +55,6 @@ mod id {
     pub struct ComponentIdPath(pub Vec<ComponentId>);
 
     impl ComponentIdPath {
-        #[expect(unused)]
         pub fn find_deepest_available_component<'a>(
             &self,
             root: &'a dyn super::Component,
@@ -168,6 +169,34 @@ impl HandleEventSuccess {
     }
 }
 
+/// The keys a component responds to, listed in the help overlay while the component is focused
+/// or contains the focus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyHints {
+    /// What the keys apply to, such as the name of a pane.
+    pub context: Cow<'static, str>,
+    /// Pairs of keys, written like in the keybinding configuration, and what they do.
+    pub hints: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+}
+
+impl KeyHints {
+    pub fn new(context: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            context: context.into(),
+            hints: Vec::new(),
+        }
+    }
+
+    pub fn with_hint(
+        mut self,
+        keys: impl Into<Cow<'static, str>>,
+        description: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.hints.push((keys.into(), description.into()));
+        self
+    }
+}
+
 /// `Component` is a trait that represents a visual and interactive element of the user interface.
 ///
 /// Implementors of this trait can be registered with the main application loop and will be able to
@@ -178,6 +207,11 @@ pub trait Component: Debug {
         Ok(HandleEventSuccess::unhandled())
     }
 
+    /// Handle events when focused while disabled. By default, events are left to the ancestors.
+    fn handle_event_disabled(&mut self, _event: &Event) -> Result<HandleEventSuccess> {
+        Ok(HandleEventSuccess::unhandled())
+    }
+
     fn update(&mut self, _message: ComponentMessage) -> Result<Option<Action>> {
         Ok(None)
     }
@@ -196,6 +230,25 @@ pub trait Component: Debug {
         false
     }
 
+    /// Returns `false` iff this component is disabled, in which case it is drawn dimmed, cannot
+    /// be focused and handles events through `handle_event_disabled` instead.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Enables or disables this component. By default, all children are enabled or disabled.
+    fn set_enabled(&mut self, enabled: bool) {
+        let _ = self.for_each_child_mut(&mut |child| {
+            child.set_enabled(enabled);
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Returns the keys handled by this component, to be listed in the help overlay.
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        None
+    }
+
     fn get_children(&self) -> Vec<&dyn Component> {
         Default::default()
     }
@@ -236,6 +289,8 @@ pub struct DrawContext<'a, 'b: 'a> {
     now: Instant,
     /// Time elapsed since the app was launched until `now`.
     elapsed_time: Duration,
+    /// The areas components were drawn into, in the order they were drawn.
+    drawn_areas: &'a mut Vec<(ComponentId, Rect)>,
 }
 
 impl<'a, 'b: 'a> DrawContext<'a, 'b> {
@@ -244,12 +299,14 @@ impl<'a, 'b: 'a> DrawContext<'a, 'b> {
         focused_id: ComponentId,
         now: Instant,
         elapsed_time: Duration,
+        drawn_areas: &'a mut Vec<(ComponentId, Rect)>,
     ) -> Self {
         Self {
             frame,
             focused_id,
             now,
             elapsed_time,
+            drawn_areas,
         }
     }
 
@@ -268,6 +325,19 @@ impl<'a, 'b: 'a> DrawContext<'a, 'b> {
     pub fn elapsed_time(&self) -> Duration {
         self.elapsed_time
     }
+
+    /// Records the area a component is drawn into, so that mouse events can be routed to it.
+    /// Components must record their area before drawing their children.
+    pub fn record_area(&mut self, id: ComponentId, area: Rect) {
+        self.drawn_areas.push((id, area));
+    }
+
+    /// Dims the already drawn contents of the area, as done for disabled components.
+    pub fn dim(&mut self, area: Rect) {
+        self.frame
+            .buffer_mut()
+            .set_style(area, Style::new().fg(ColorOklch::new(0.4, 0.0, 0.0).into()));
+    }
 }
 
 /// A drawable element (usually a `Component`).
@@ -342,7 +412,7 @@ pub fn find_child_by_id(
     child_id: ComponentId,
 ) -> Option<&dyn Component> {
     for_each_child(component, |child| {
-        if child.is_focusable() && child.get_id() == child_id {
+        if accepts_focus(child) && child.get_id() == child_id {
             ControlFlow::Break(child)
         } else {
             ControlFlow::Continue(())
@@ -356,7 +426,7 @@ pub fn find_child_by_id_mut(
     child_id: ComponentId,
 ) -> Option<&mut dyn Component> {
     for_each_child_mut(component, |child| {
-        if child.is_focusable() && child.get_id() == child_id {
+        if accepts_focus(child) && child.get_id() == child_id {
             ControlFlow::Break(child)
         } else {
             ControlFlow::Continue(())
@@ -400,6 +470,60 @@ pub fn depth_first_search_mut<'a, B: 'a>(
     ControlFlow::Continue(())
 }
 
+/// Returns `true` iff the component can currently be focused, that is, it is both focusable and
+/// enabled.
+pub fn accepts_focus(component: &dyn Component) -> bool {
+    component.is_focusable() && component.is_enabled()
+}
+
+/// Returns `true` iff the component or any of its descendants accepts focus.
+pub fn contains_focusable(subtree_root: &dyn Component) -> bool {
+    depth_first_search(
+        subtree_root,
+        &mut |component| {
+            if accepts_focus(component) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+        &mut |_| ControlFlow::Continue(()),
+    )
+    .is_break()
+}
+
+/// Returns the first component accepting focus within the subtree in depth-first order, if any.
+pub fn find_first_focusable(subtree_root: &dyn Component) -> Option<&dyn Component> {
+    depth_first_search(
+        subtree_root,
+        &mut |component| {
+            if accepts_focus(component) {
+                ControlFlow::Break(component)
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+        &mut |_| ControlFlow::Continue(()),
+    )
+    .break_value()
+}
+
+/// Returns `true` iff the component with the ID `id` is the subtree root or one of its descendants.
+pub fn contains_component(subtree_root: &dyn Component, id: ComponentId) -> bool {
+    depth_first_search(
+        subtree_root,
+        &mut |component| {
+            if component.get_id() == id {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+        &mut |_| ControlFlow::Continue(()),
+    )
+    .is_break()
+}
+
 pub fn find_component_by_id_mut(
     subtree_root: &mut dyn Component,
     id: ComponentId,
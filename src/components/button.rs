@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     layout::{Rect, Size},
     style::{Style, Stylize},
@@ -12,7 +12,7 @@ use tokio::sync::mpsc::UnboundedSender;
 use crate::{
     action::{Action, ComponentMessage},
     color::TextColor,
-    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess},
+    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints},
     rect::{LineAlignment, PlaneAlignment, RectExt},
     tui::Event,
 };
@@ -28,6 +28,7 @@ pub struct Button {
     pub held_down: bool,
     /// Whether the button is to be used for form confirmation.
     pub form_confirmation: bool,
+    enabled: bool,
 }
 
 impl Button {
@@ -43,6 +44,7 @@ impl Button {
             text_color_pressed: Default::default(),
             held_down: false,
             form_confirmation: false,
+            enabled: true,
             action_tx: tx.clone(),
         }
     }
@@ -81,8 +83,18 @@ impl Component for Button {
         true
     }
 
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.held_down &= enabled;
+    }
+
     fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
-        if self.form_confirmation {
+        // Form confirmation buttons leave the Enter key to the form, but may still be clicked.
+        if self.form_confirmation && matches!(event, Event::Key(_)) {
             return Ok(HandleEventSuccess::unhandled());
         }
 
@@ -91,6 +103,10 @@ impl Component for Button {
                 code: KeyCode::Enter,
                 kind: KeyEventKind::Press,
                 ..
+            })
+            | Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                ..
             }) => {
                 self.held_down = true;
                 self.action_tx
@@ -105,6 +121,10 @@ impl Component for Button {
                 kind: KeyEventKind::Release,
                 ..
             })
+            | Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                ..
+            })
             | Event::FocusLost => {
                 self.held_down = false;
                 tracing::debug!("RELEASE");
@@ -114,6 +134,12 @@ impl Component for Button {
         })
     }
 
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        // Form confirmation buttons are pressed through the form.
+        (!self.form_confirmation)
+            .then(|| KeyHints::new("Button").with_hint("<Enter>", format!("Press {}", self.label)))
+    }
+
     fn get_id(&self) -> ComponentId {
         self.id
     }
@@ -138,7 +164,8 @@ impl Drawable for Button {
         }
 
         area.height = 1;
-        let focused = context.focused_id() == self.id;
+        context.record_area(self.id, area);
+        let focused = context.focused_id() == self.id && self.enabled;
         let text_color = if self.held_down {
             &self.text_color_pressed
         } else if focused {
@@ -153,6 +180,10 @@ impl Drawable for Button {
         context.frame().render_widget(span, span_area);
         context.frame().buffer_mut().set_style(area, text_color);
 
+        if !self.enabled {
+            context.dim(area);
+        }
+
         Ok(())
     }
 }
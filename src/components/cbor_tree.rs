@@ -0,0 +1,469 @@
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind};
+use itertools::Itertools;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use rrr::{cbor, record::RecordMetadataKey};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    cbor::{cbor_value_to_diagnostic, cbor_value_to_line, decode_embedded_cbor},
+    color::{ColorOklch, TextColor},
+    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints},
+    tui::Event,
+};
+
+use super::scroll_view::{MOUSE_SCROLL_ROWS, ScrollContent, ScrollView, ScrollViewArgs};
+
+/// How a top-level value is referred to.
+#[derive(Debug, Clone)]
+pub enum CborTreeLabel {
+    None,
+    Name(String),
+    Key(cbor::Value),
+}
+
+impl From<RecordMetadataKey<'_>> for CborTreeLabel {
+    fn from(key: RecordMetadataKey<'_>) -> Self {
+        match key {
+            RecordMetadataKey::Id(id) => Self::Name(id.to_string()),
+            RecordMetadataKey::Custom(key) => Self::Key(key.0.clone()),
+        }
+    }
+}
+
+/// Where the value of a node is stored within a `CborTree`.
+#[derive(Debug, Clone, Copy)]
+enum CborTreeNodeValue {
+    /// The `index`-th top-level entry.
+    Entry(usize),
+    /// The `index`-th item of the value of the `parent` node.
+    Child { parent: usize, index: usize },
+    /// The `index`-th embedded CBOR item, decoded when building the tree.
+    Embedded(usize),
+}
+
+/// How a node's value is referred to within its parent, borrowed from the tree.
+enum CborTreeNodeLabel<'a> {
+    None,
+    Index(usize),
+    Name(&'a str),
+    Key(&'a cbor::Value),
+}
+
+#[derive(Debug, Clone)]
+struct CborTreeNode {
+    value: CborTreeNodeValue,
+    depth: usize,
+    /// The number of descendants of this node, which immediately follow it in pre-order.
+    descendant_count: usize,
+    expanded: bool,
+}
+
+/// A collapsible tree of CBOR values, with nested arrays and maps indented below their parents.
+#[derive(Debug)]
+pub struct CborTree {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    /// The top-level values, which all nodes refer into.
+    entries: Vec<(CborTreeLabel, cbor::Value)>,
+    /// The CBOR items embedded within the values, which their nodes refer into.
+    embedded: Vec<cbor::Value>,
+    /// All nodes in pre-order.
+    nodes: Vec<CborTreeNode>,
+    selected: usize,
+    scroll_view: ScrollView,
+}
+
+impl CborTree {
+    pub fn new(id: ComponentId, action_tx: &UnboundedSender<Action>) -> Self {
+        Self {
+            id,
+            action_tx: action_tx.clone(),
+            entries: Default::default(),
+            embedded: Default::default(),
+            nodes: Default::default(),
+            selected: 0,
+            scroll_view: ScrollView::new(ComponentId::new()),
+        }
+    }
+
+    /// Replaces the displayed values with `entries`, each becoming a top-level node.
+    pub fn set_entries<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = (CborTreeLabel, &'a cbor::Value)>,
+    ) {
+        self.entries = entries
+            .into_iter()
+            .map(|(label, value)| (label, value.clone()))
+            .collect();
+        self.embedded.clear();
+        self.nodes.clear();
+
+        for index in 0..self.entries.len() {
+            self.push_node(CborTreeNodeValue::Entry(index), 0);
+        }
+
+        self.selected = 0;
+        self.scroll_view.scroll_to_top();
+    }
+
+    pub fn clear(&mut self) {
+        self.set_entries([]);
+    }
+
+    /// Appends the node referring to `value` and its descendants in pre-order.
+    fn push_node(&mut self, value: CborTreeNodeValue, depth: usize) {
+        let is_container =
+            |value: &cbor::Value| matches!(value, cbor::Value::Array(_) | cbor::Value::Map(_));
+        let index = self.nodes.len();
+
+        self.nodes.push(CborTreeNode {
+            value,
+            depth,
+            descendant_count: 0,
+            // Only the top-level values are expanded initially.
+            expanded: depth == 0,
+        });
+
+        let mut embedded = None;
+        let child_count = match self.get_value(index) {
+            cbor::Value::Array(array) => array.len(),
+            cbor::Value::Map(map) => map.len(),
+            // Embedded CBOR containers are expanded in place of their encoding.
+            value @ cbor::Value::Tag(_, _)
+                if let Some(embedded_value) = decode_embedded_cbor(value)
+                    && is_container(&embedded_value) =>
+            {
+                embedded = Some(embedded_value);
+                0
+            }
+            cbor::Value::Tag(_, inner) if is_container(inner) => 1,
+            _ => 0,
+        };
+
+        if let Some(embedded) = embedded {
+            self.embedded.push(embedded);
+            self.push_node(
+                CborTreeNodeValue::Embedded(self.embedded.len() - 1),
+                depth + 1,
+            );
+        }
+
+        for child_index in 0..child_count {
+            self.push_node(
+                CborTreeNodeValue::Child {
+                    parent: index,
+                    index: child_index,
+                },
+                depth + 1,
+            );
+        }
+
+        self.nodes[index].descendant_count = self.nodes.len() - index - 1;
+    }
+
+    fn get_value(&self, index: usize) -> &cbor::Value {
+        match self.nodes[index].value {
+            CborTreeNodeValue::Entry(index) => &self.entries[index].1,
+            CborTreeNodeValue::Embedded(index) => &self.embedded[index],
+            CborTreeNodeValue::Child { parent, index } => match self.get_value(parent) {
+                cbor::Value::Array(array) => &array[index],
+                cbor::Value::Map(map) => &map[index].1,
+                cbor::Value::Tag(_, inner) => inner,
+                _ => unreachable!("only containers and tags have children"),
+            },
+        }
+    }
+
+    fn get_label(&self, index: usize) -> CborTreeNodeLabel<'_> {
+        match self.nodes[index].value {
+            CborTreeNodeValue::Entry(index) => match &self.entries[index].0 {
+                CborTreeLabel::None => CborTreeNodeLabel::None,
+                CborTreeLabel::Name(name) => CborTreeNodeLabel::Name(name),
+                CborTreeLabel::Key(key) => CborTreeNodeLabel::Key(key),
+            },
+            CborTreeNodeValue::Embedded(_) => CborTreeNodeLabel::None,
+            CborTreeNodeValue::Child { parent, index } => match self.get_value(parent) {
+                cbor::Value::Array(_) => CborTreeNodeLabel::Index(index),
+                cbor::Value::Map(map) => CborTreeNodeLabel::Key(&map[index].0),
+                _ => CborTreeNodeLabel::None,
+            },
+        }
+    }
+
+    fn get_summary(&self, index: usize) -> Line<'_> {
+        let type_label = |type_label: String| {
+            Span::styled(
+                type_label,
+                TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0)),
+            )
+        };
+
+        match self.get_value(index) {
+            cbor::Value::Array(array) => {
+                Line::from(type_label(format!("array ({} items)", array.len())))
+            }
+            cbor::Value::Map(map) => Line::from(type_label(format!("map ({} entries)", map.len()))),
+            value @ cbor::Value::Tag(_, _) if decode_embedded_cbor(value).is_some() => {
+                Line::from(type_label("cbor".into()))
+            }
+            cbor::Value::Tag(tag, _) if self.nodes[index].descendant_count > 0 => {
+                Line::from(type_label(format!("tag({tag})")))
+            }
+            value => cbor_value_to_line(value),
+        }
+    }
+
+    /// The text placed into the clipboard when copying the value.
+    fn get_copy_text(&self, index: usize) -> String {
+        match self.get_value(index) {
+            cbor::Value::Text(text) => text.clone(),
+            cbor::Value::Bytes(bytes) => format!("{:02x}", bytes.iter().format("")),
+            value => cbor_value_to_diagnostic(value),
+        }
+    }
+
+    /// Indices of the nodes not hidden within collapsed nodes.
+    fn visible_rows(&self) -> Vec<usize> {
+        let mut rows = Vec::new();
+        let mut index = 0;
+
+        while let Some(node) = self.nodes.get(index) {
+            rows.push(index);
+            index += if node.expanded {
+                1
+            } else {
+                node.descendant_count + 1
+            };
+        }
+
+        rows
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let rows = self.visible_rows();
+        let current_row = rows
+            .iter()
+            .position(|index| *index == self.selected)
+            .unwrap_or(0);
+        let new_row = current_row
+            .saturating_add_signed(delta)
+            .min(rows.len().saturating_sub(1));
+
+        if let Some(index) = rows.get(new_row) {
+            self.selected = *index;
+        }
+    }
+
+    fn get_parent(&self, index: usize) -> Option<usize> {
+        let depth = self.nodes[index].depth;
+
+        self.nodes[..index]
+            .iter()
+            .rposition(|node| node.depth < depth)
+    }
+
+    fn expand_or_descend(&mut self) {
+        let Some(node) = self.nodes.get_mut(self.selected) else {
+            return;
+        };
+
+        if node.descendant_count == 0 {
+            return;
+        }
+
+        if !node.expanded {
+            node.expanded = true;
+        } else {
+            self.selected += 1;
+        }
+    }
+
+    fn collapse_or_ascend(&mut self) {
+        let Some(node) = self.nodes.get_mut(self.selected) else {
+            return;
+        };
+
+        if node.expanded && node.descendant_count > 0 {
+            node.expanded = false;
+        } else if let Some(parent) = self.get_parent(self.selected) {
+            self.selected = parent;
+        }
+    }
+
+    fn get_row_line(&self, index: usize) -> Line<'_> {
+        let node = &self.nodes[index];
+        let gray = TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0));
+        let marker = if node.descendant_count == 0 {
+            "  "
+        } else if node.expanded {
+            "▾ "
+        } else {
+            "▸ "
+        };
+        let mut line = Line::from_iter([Span::styled(
+            format!("{}{marker}", "  ".repeat(node.depth)),
+            &gray,
+        )]);
+
+        match self.get_label(index) {
+            CborTreeNodeLabel::None => {}
+            CborTreeNodeLabel::Index(index) => {
+                line.push_span(Span::styled(format!("[{index}]"), &gray));
+                line.push_span(Span::styled(": ", &gray));
+            }
+            CborTreeNodeLabel::Name(name) => {
+                line.push_span(Span::raw(name));
+                line.push_span(Span::styled(": ", &gray));
+            }
+            CborTreeNodeLabel::Key(key) => {
+                line.extend(cbor_value_to_line(key).spans);
+                line.push_span(Span::styled(": ", &gray));
+            }
+        }
+
+        line.extend(self.get_summary(index).spans);
+        line
+    }
+}
+
+/// The visible rows of a `CborTree`, each formatted only once it is scrolled into view.
+struct CborTreeRows<'a> {
+    tree: &'a CborTree,
+    /// The indices of the nodes displayed in the rows.
+    rows: Vec<usize>,
+    /// The index of the node to highlight, if any.
+    highlighted: Option<usize>,
+}
+
+impl ScrollContent for CborTreeRows<'_> {
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn render_scrolled(self: Box<Self>, area: Rect, buf: &mut Buffer, offset: usize) {
+        for (y, index) in (area.y..area.bottom()).zip(self.rows.iter().skip(offset)) {
+            let line = self.tree.get_row_line(*index);
+            let line = if self.highlighted == Some(*index) {
+                line.patch_style(Style::new().reversed())
+            } else {
+                line
+            };
+
+            line.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+}
+
+impl Component for CborTree {
+    fn is_focusable(&self) -> bool {
+        !self.nodes.is_empty()
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        if let Event::Mouse(MouseEvent { kind, .. }) = event {
+            match kind {
+                MouseEventKind::ScrollUp => self.move_selection(-MOUSE_SCROLL_ROWS),
+                MouseEventKind::ScrollDown => self.move_selection(MOUSE_SCROLL_ROWS),
+                _ => return Ok(HandleEventSuccess::unhandled()),
+            }
+
+            return Ok(HandleEventSuccess::handled().with_action(Action::Render));
+        }
+
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event
+        else {
+            return Ok(HandleEventSuccess::unhandled());
+        };
+
+        match code {
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::PageUp => self.move_selection(-8),
+            KeyCode::PageDown => self.move_selection(8),
+            KeyCode::Right => self.expand_or_descend(),
+            KeyCode::Left => self.collapse_or_ascend(),
+            KeyCode::Char(' ') => {
+                if let Some(node) = self.nodes.get_mut(self.selected)
+                    && node.descendant_count > 0
+                {
+                    node.expanded = !node.expanded;
+                }
+            }
+            KeyCode::Char('y') if *modifiers == KeyModifiers::NONE => {
+                if self.selected < self.nodes.len() {
+                    self.action_tx
+                        .send(Action::CopyToClipboard(self.get_copy_text(self.selected)))?;
+                }
+            }
+            _ => return Ok(HandleEventSuccess::unhandled()),
+        }
+
+        Ok(HandleEventSuccess::handled().with_action(Action::Render))
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Value Tree")
+                .with_hint("<Up> <Down>", "Select the previous or next value")
+                .with_hint("<PageUp> <PageDown>", "Move the selection by a page")
+                .with_hint("<Right>", "Expand or select the first inner value")
+                .with_hint("<Left>", "Collapse or select the enclosing value")
+                .with_hint("<Space>", "Expand or collapse")
+                .with_hint("<y>", "Copy the selected value"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+}
+
+impl Drawable for CborTree {
+    type Args<'a>
+        = ()
+    where
+        Self: 'a;
+
+    fn draw<'a>(&self, context: &mut DrawContext, area: Rect, (): Self::Args<'a>) -> Result<()>
+    where
+        Self: 'a,
+    {
+        context.record_area(self.id, area);
+        let focused = context.focused_id() == self.id;
+        let rows = self.visible_rows();
+        let selected_row = rows
+            .iter()
+            .position(|index| *index == self.selected)
+            .unwrap_or(0);
+        self.scroll_view.draw(
+            context,
+            area,
+            ScrollViewArgs::new(CborTreeRows {
+                tree: self,
+                rows,
+                highlighted: focused.then_some(self.selected),
+            })
+            .with_revealed_row(selected_row),
+        )
+    }
+}
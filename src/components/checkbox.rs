@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     layout::{Rect, Size},
     style::{Style, Stylize},
@@ -11,7 +11,7 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     action::{Action, ComponentMessage},
-    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess},
+    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints},
     tui::Event,
 };
 
@@ -22,6 +22,7 @@ pub struct Checkbox {
     pub checked: bool,
     string_checked: Cow<'static, str>,
     string_unchecked: Cow<'static, str>,
+    enabled: bool,
     action_tx: UnboundedSender<Action>,
 }
 
@@ -41,6 +42,7 @@ impl Checkbox {
             checked,
             string_checked: "[x]".into(),
             string_unchecked: "[ ]".into(),
+            enabled: true,
             action_tx: tx.clone(),
         }
     }
@@ -74,12 +76,24 @@ impl Component for Checkbox {
         true
     }
 
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
     fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
         Ok(match event {
             Event::Key(KeyEvent {
                 code: KeyCode::Char(' '),
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
                 ..
+            })
+            | Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                ..
             }) => {
                 self.checked = !self.checked;
                 self.action_tx.send(Action::BroadcastMessage(
@@ -94,6 +108,10 @@ impl Component for Checkbox {
         })
     }
 
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(KeyHints::new("Checkbox").with_hint("<Space>", "Check or uncheck"))
+    }
+
     fn get_id(&self) -> ComponentId {
         self.id
     }
@@ -118,7 +136,8 @@ impl Drawable for Checkbox {
         }
 
         area.height = 1;
-        let focused = context.focused_id() == self.id;
+        context.record_area(self.id, area);
+        let focused = context.focused_id() == self.id && self.enabled;
         let checkmark_style = if focused {
             Style::new().reversed()
         } else {
@@ -137,6 +156,10 @@ impl Drawable for Checkbox {
 
         context.frame().render_widget(Line::from_iter(spans), area);
 
+        if !self.enabled {
+            context.dim(area);
+        }
+
         Ok(())
     }
 }
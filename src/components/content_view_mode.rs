@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use ratatui::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::color::{ColorOklch, TextColor};
+
+/// How the record data is displayed in the content pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentViewMode {
+    /// Chosen based on the data being displayed.
+    #[default]
+    Auto,
+    Text,
+    Hex,
+    Cbor,
+}
+
+impl Display for ContentViewMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Text => write!(f, "text"),
+            Self::Hex => write!(f, "hex"),
+            Self::Cbor => write!(f, "CBOR"),
+        }
+    }
+}
+
+impl ContentViewMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Auto => Self::Text,
+            Self::Text => Self::Hex,
+            Self::Hex => Self::Cbor,
+            Self::Cbor => Self::Auto,
+        }
+    }
+
+    /// Resolves `Auto` to the most suitable mode for the data.
+    pub fn resolve(self, data: &[u8]) -> Self {
+        match self {
+            Self::Auto => {
+                let readable_text = std::str::from_utf8(data).is_ok_and(|text| {
+                    !text
+                        .chars()
+                        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+                });
+
+                if readable_text {
+                    Self::Text
+                } else if crate::cbor::parse_cbor(data).is_some() {
+                    Self::Cbor
+                } else {
+                    Self::Hex
+                }
+            }
+            mode => mode,
+        }
+    }
+
+    /// Formats the data for display in an area of the given width.
+    /// Must not be called with `Auto`, which needs to be resolved first.
+    pub fn format(self, data: &[u8], width: u16) -> Text<'static> {
+        let wrap = |string: &str| {
+            Text::from_iter(
+                textwrap::wrap(string, textwrap::Options::new(width as usize))
+                    .into_iter()
+                    .map(|line| Line::from(line.into_owned())),
+            )
+        };
+
+        match self {
+            Self::Text => wrap(&String::from_utf8_lossy(data)),
+            Self::Hex => Text::from(crate::hex::hex_dump_lines(data, width)),
+            Self::Cbor => match crate::cbor::parse_cbor(data) {
+                Some(value) => wrap(&crate::cbor::cbor_value_to_diagnostic(&value)),
+                None => {
+                    let mut text = Text::from(Line::styled(
+                        "(not valid CBOR)",
+                        TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0)),
+                    ));
+                    text.extend(crate::hex::hex_dump_lines(data, width));
+                    text
+                }
+            },
+            Self::Auto => unreachable!("the view mode must be resolved before formatting"),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use color_eyre::eyre::{Result, eyre};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Hex,
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Utf8 => write!(f, "UTF-8"),
+            Self::Hex => write!(f, "Hexadecimal Byte String"),
+        }
+    }
+}
+
+impl Encoding {
+    /// Encodes `bytes` for editing in a single-line field, unless they cannot be represented in
+    /// this encoding. UTF-8 text containing control characters, such as line breaks, is rejected.
+    pub fn encode(&self, bytes: &[u8]) -> Option<String> {
+        match self {
+            Self::Utf8 => std::str::from_utf8(bytes)
+                .ok()
+                .filter(|text| !text.chars().any(char::is_control))
+                .map(str::to_string),
+            Self::Hex => Some(format!("{:02x}", bytes.iter().format(""))),
+        }
+    }
+
+    /// Decodes the user input into bytes, such as those of a record name.
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>> {
+        match self {
+            Self::Utf8 => Ok(input.as_bytes().into()),
+            Self::Hex => {
+                // Whitespace may be used to group digits, and each group may carry a `0x` prefix.
+                let digits = input
+                    .split_whitespace()
+                    .map(|group| {
+                        group
+                            .strip_prefix("0x")
+                            .or_else(|| group.strip_prefix("0X"))
+                            .unwrap_or(group)
+                    })
+                    .collect::<String>();
+
+                if let Some(invalid_char) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+                    return Err(eyre!("Invalid hex: {invalid_char:?}"));
+                }
+
+                if digits.len() % 2 != 0 {
+                    return Err(eyre!("Odd hex length"));
+                }
+
+                Ok((0..digits.len())
+                    .step_by(2)
+                    .map(|index| u8::from_str_radix(&digits[index..index + 2], 16).unwrap())
+                    .collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn hex_decode_groups() {
+        assert_eq!(
+            Encoding::Hex.decode("0x01 02AB 0Xff").unwrap(),
+            vec![0x01, 0x02, 0xab, 0xff]
+        );
+        assert_eq!(Encoding::Hex.decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn hex_decode_odd_length() {
+        assert_eq!(
+            Encoding::Hex.decode("abc").unwrap_err().to_string(),
+            "Odd hex length"
+        );
+        // Only the length of all groups together has to be even.
+        assert_eq!(Encoding::Hex.decode("a b").unwrap(), vec![0xab]);
+    }
+
+    #[test]
+    fn hex_decode_invalid() {
+        assert_eq!(
+            Encoding::Hex.decode("0g").unwrap_err().to_string(),
+            "Invalid hex: 'g'"
+        );
+        assert_eq!(
+            Encoding::Hex.decode("0x0x01").unwrap_err().to_string(),
+            "Invalid hex: 'x'"
+        );
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let bytes = [0x00, 0x7f, 0x80, 0xff];
+        let encoded = Encoding::Hex.encode(&bytes).unwrap();
+
+        assert_eq!(encoded, "007f80ff");
+        assert_eq!(Encoding::Hex.decode(&encoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn utf8_round_trip() {
+        let encoded = Encoding::Utf8.encode("žluťoučký".as_bytes()).unwrap();
+
+        assert_eq!(encoded, "žluťoučký");
+        assert_eq!(
+            Encoding::Utf8.decode(&encoded).unwrap(),
+            "žluťoučký".as_bytes()
+        );
+    }
+
+    #[test]
+    fn utf8_encode_unrepresentable() {
+        assert_eq!(Encoding::Utf8.encode(&[0xff]), None);
+        assert_eq!(Encoding::Utf8.encode(b"line\nbreak"), None);
+    }
+}
@@ -0,0 +1,91 @@
+use color_eyre::Result;
+use ratatui::{
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{Block, Clear, Padding},
+};
+
+use crate::{
+    color::{ColorOklch, TextColor},
+    component::{DrawContext, Drawable, KeyHints},
+    components::scroll_view::{ScrollView, ScrollViewArgs},
+    rect::{LineAlignment, PlaneAlignment, RectExt},
+};
+
+/// The maximum width of the overlay, including its border.
+const MAX_WIDTH: u16 = 80;
+
+/// A list of key hints grouped by the context they apply to, drawn centered over the user
+/// interface. The hints are scrolled by `scroll_view` when they do not fit the screen.
+pub struct HelpOverlay<'a> {
+    /// The most specific context comes first.
+    pub sections: Vec<KeyHints>,
+    pub scroll_view: &'a ScrollView,
+}
+
+impl HelpOverlay<'_> {
+    fn get_text(&self) -> Text<'_> {
+        let keys_width = self
+            .sections
+            .iter()
+            .flat_map(|section| &section.hints)
+            .map(|(keys, _)| Span::raw(keys.as_ref()).width())
+            .max()
+            .unwrap_or(0);
+        let keys_color = TextColor::default().fg(ColorOklch::new(0.79, 0.1603, 67.76 / 360.0));
+        let mut lines = Vec::new();
+
+        for section in &self.sections {
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+
+            lines.push(Line::styled(section.context.as_ref(), Style::new().bold()));
+            lines.extend(section.hints.iter().map(|(keys, description)| {
+                Line::from_iter([
+                    Span::styled(format!("  {keys:keys_width$}  "), &keys_color),
+                    Span::raw(description.as_ref()),
+                ])
+            }));
+        }
+
+        Text::from(lines)
+    }
+}
+
+impl HelpOverlay<'_> {
+    pub fn draw(self, context: &mut DrawContext, area: Rect) -> Result<()> {
+        let text = self.get_text();
+        let scrollable = text.height() as u16 + 2 > area.height;
+        // The border, a column of padding on the left and the scrollbar on the right.
+        let size = Size::new(
+            (text.width() as u16 + 4).min(MAX_WIDTH).min(area.width),
+            (text.height() as u16 + 2).min(area.height),
+        );
+        let area = area.align(
+            size,
+            PlaneAlignment {
+                x: LineAlignment::Center,
+                y: LineAlignment::Center,
+            },
+        );
+        let block = Block::bordered()
+            .title(" Help ")
+            .title_bottom(
+                Line::from(if scrollable {
+                    " Scroll with <Up> <Down>, close with any other key "
+                } else {
+                    " Press any key to close "
+                })
+                .right_aligned(),
+            )
+            .style(TextColor::default());
+        let area_text = block.inner(area).without_padding(Padding::left(1));
+
+        context.frame().render_widget(Clear, area);
+        context.frame().render_widget(block, area);
+        self.scroll_view
+            .draw(context, area_text, ScrollViewArgs::new(text))
+    }
+}
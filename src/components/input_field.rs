@@ -1,7 +1,9 @@
-use std::ops::Range;
+use std::{cell::Cell, ops::Range};
 
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use ratatui::{
     layout::Rect,
     style::{Color, Style, Stylize},
@@ -11,7 +13,7 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     action::Action,
-    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess},
+    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints},
     tui::Event,
 };
 
@@ -75,6 +77,11 @@ pub struct InputField {
     id: ComponentId,
     cursor: Cursor,
     content: String,
+    /// The area of the field as of the last draw, used to place the cursor on click.
+    area: Cell<Rect>,
+    /// The position of the first visible character as of the last draw, in bytes.
+    scroll_position: Cell<usize>,
+    enabled: bool,
 }
 
 impl InputField {
@@ -86,6 +93,9 @@ impl InputField {
             id,
             cursor: Cursor::default(),
             content: String::new(),
+            area: Default::default(),
+            scroll_position: Default::default(),
+            enabled: true,
         }
     }
 
@@ -167,6 +177,45 @@ impl InputField {
             .map(|delta| (position as isize + delta) as usize)
     }
 
+    /// The position of the character boundary closest to the left of `column`, in bytes.
+    fn get_position_at_column(&self, column: u16) -> usize {
+        let column = column.saturating_sub(self.area.get().x) as usize;
+        let scroll_position = self.scroll_position.get();
+
+        self.content[scroll_position..]
+            .char_indices()
+            .map(|(index, _)| scroll_position + index)
+            .chain([self.content.len()])
+            .take_while(|index| Span::raw(&self.content[scroll_position..*index]).width() <= column)
+            .last()
+            .unwrap_or(scroll_position)
+    }
+
+    /// Scrolls the content horizontally, so that the cursor is visible within `width` columns.
+    /// Returns the position of the first visible character, in bytes.
+    fn update_scroll_position(&self, width: u16) -> usize {
+        let cursor = self.cursor.end;
+        // One column is kept free for the cursor past the end of the content.
+        let columns = (width as usize).saturating_sub(1);
+        let earliest_position = self.content[..cursor]
+            .char_indices()
+            .rev()
+            .map(|(index, _)| index)
+            .take_while(|index| Span::raw(&self.content[*index..cursor]).width() <= columns)
+            .last()
+            .unwrap_or(cursor);
+        let scroll_position = self.scroll_position.get();
+        let scroll_position =
+            if scroll_position <= cursor && self.content.is_char_boundary(scroll_position) {
+                scroll_position.max(earliest_position)
+            } else {
+                cursor
+            };
+
+        self.scroll_position.set(scroll_position);
+        scroll_position
+    }
+
     pub fn get_content(&self) -> &str {
         &self.content
     }
@@ -175,6 +224,12 @@ impl InputField {
         self.content = "".into();
         self.cursor = Cursor::default();
     }
+
+    /// Replaces the content, placing the cursor at its end.
+    pub fn set_content(&mut self, content: String) {
+        self.cursor = Cursor::at(content.len());
+        self.content = content;
+    }
 }
 
 impl Component for InputField {
@@ -182,11 +237,32 @@ impl Component for InputField {
         true
     }
 
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn handle_event_disabled(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        // Typed characters must not reach the key bindings of the ancestors, such as quitting.
+        Ok(match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Char(_),
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+                ..
+            }) => HandleEventSuccess::handled(),
+            _ => HandleEventSuccess::unhandled(),
+        })
+    }
+
     fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
         Ok(match event {
             Event::Key(KeyEvent {
                 code: KeyCode::Char(character),
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
                 ..
             }) => {
                 let string = character.to_string();
@@ -247,6 +323,22 @@ impl Component for InputField {
 
                 HandleEventSuccess::handled().with_action(Action::Render)
             }
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                ..
+            }) => {
+                self.cursor = Cursor::at(self.get_position_at_column(*column));
+                HandleEventSuccess::handled().with_action(Action::Render)
+            }
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column,
+                ..
+            }) => {
+                self.cursor.end = self.get_position_at_column(*column);
+                HandleEventSuccess::handled().with_action(Action::Render)
+            }
             Event::Paste(paste_string) => {
                 self.insert(paste_string);
                 HandleEventSuccess::handled().with_action(Action::Render)
@@ -262,6 +354,15 @@ impl Component for InputField {
         })
     }
 
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Text Field")
+                .with_hint("<Left> <Right>", "Move the cursor")
+                .with_hint("<Shift-Left> <Shift-Right>", "Select text")
+                .with_hint("<Backspace> <Delete>", "Delete text"),
+        )
+    }
+
     fn get_id(&self) -> ComponentId {
         self.id
     }
@@ -282,16 +383,22 @@ impl Drawable for InputField {
         }
 
         area.height = 1;
+        context.record_area(self.id, area);
+        self.area.set(area);
 
-        let focused = context.focused_id() == self.id;
+        let focused = context.focused_id() == self.id && self.enabled;
+        let scroll_position = self.update_scroll_position(area.width);
+        let content = &self.content[scroll_position..];
 
         if focused {
             let minmax = self.cursor.minmax();
+            let minmax = minmax.start.saturating_sub(scroll_position)
+                ..minmax.end.saturating_sub(scroll_position);
 
             if minmax.is_empty() {
-                let mut spans = vec![Span::styled(&self.content[..minmax.start], Style::new())];
-                if minmax.start < self.content.len() {
-                    let mut chars = self.content[minmax.start..].chars();
+                let mut spans = vec![Span::styled(&content[..minmax.start], Style::new())];
+                if minmax.start < content.len() {
+                    let mut chars = content[minmax.start..].chars();
                     let cursor_char = chars.next().into_iter().collect::<String>();
                     let remaining = chars.collect::<String>();
                     spans.extend([
@@ -304,19 +411,23 @@ impl Drawable for InputField {
                 context.frame().render_widget(Line::from(spans), area);
             } else {
                 let spans = vec![
-                    Span::styled(&self.content[..minmax.start], Style::new()),
+                    Span::styled(&content[..minmax.start], Style::new()),
                     Span::styled(
-                        &self.content[minmax.start..minmax.end],
+                        &content[minmax.start..minmax.end],
                         Style::new().white().bg(Color::Rgb(0x5F, 0x5F, 0x5F)),
                     ),
-                    Span::styled(&self.content[minmax.end..], Style::new()),
+                    Span::styled(&content[minmax.end..], Style::new()),
                 ];
                 context.frame().render_widget(Line::from(spans), area);
             }
         } else {
             context
                 .frame()
-                .render_widget(Span::styled(&self.content, Style::new()), area);
+                .render_widget(Span::styled(content, Style::new()), area);
+        }
+
+        if !self.enabled {
+            context.dim(area);
         }
 
         Ok(())
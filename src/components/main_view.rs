@@ -1,36 +1,47 @@
 use core::option::Option::Some;
+use std::borrow::Cow;
 use std::cell::RefCell;
-use std::fmt::Display;
+use std::ops::ControlFlow;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use color_eyre::eyre::{Result, eyre};
+use color_eyre::eyre::{Result, WrapErr, eyre};
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use itertools::Itertools;
 use ratatui::prelude::*;
-use ratatui::widgets::Table;
 use rrr::record::{
-    HashedRecordKey, RECORD_NAME_ROOT, RecordKey, RecordName, RecordReadVersionSuccess,
+    HashedRecordKey, RECORD_NAME_ROOT, RecordName, RecordReadVersionSuccess, RecordVersion,
     SuccessionNonce,
 };
-use rrr::registry::Registry;
-use rrr::utils::fd_lock::ReadLock;
-use rrr::utils::serde::BytesOrAscii;
+use tokio::sync::RwLock;
 use tokio::sync::mpsc::UnboundedSender;
-use tracing::{Instrument, debug, info_span};
+use tokio_util::sync::CancellationToken;
+use zeroize::ZeroizeOnDrop;
 
-use crate::action::{Action, ComponentMessage};
+use crate::action::{Action, ComponentMessage, LoadedRecordVersion, Navigation};
 use crate::args::Args;
 use crate::color::{ColorOklch, TextColor};
-use crate::component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess};
+use crate::component::{
+    self, Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints,
+};
+use crate::config::{Config, SearchConfig};
 use crate::env::PROJECT_VERSION;
-use crate::error;
+use crate::registry::{AnyRegistry, RegistryShards};
 use crate::tui::Event;
 
-use super::button::Button;
-use super::input_field::InputField;
-use super::open_status::{Animation, OpenStatus, SpinnerContent};
-use super::radio_array::RadioArray;
+use super::cbor_tree::{CborTree, CborTreeLabel};
+use super::content_view_mode::ContentViewMode;
+use super::open_status::{Animation, SpinnerContent};
+use super::pane_export::PaneExport;
+use super::pane_open::PaneOpen;
+use super::pane_overview::PaneOverview;
+use super::pane_settings::PaneSettings;
+use super::pane_versions::PaneVersions;
+use super::pane_write::{PaneWrite, WriteTarget};
+use super::record_tree::RecordTree;
+use super::scroll_view::{ScrollView, ScrollViewArgs};
+use super::version_diff::VersionDiff;
 
 #[derive(Clone)]
 pub struct LineSpacer {
@@ -87,57 +98,341 @@ impl Widget for LineSpacer {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum Encoding {
-    Utf8,
-    Hex,
+/// A final status message, fading from the accent color of `hue` to gray.
+pub fn get_status_finished(text: Cow<'static, str>, hue: f32) -> SpinnerContent<'static> {
+    let now = Instant::now();
+
+    SpinnerContent::default()
+        .with_text(text)
+        .with_animation(Some(Animation::Ease {
+            easing_function: easing_function::easings::EaseInOutCubic.into(),
+            color_start: TextColor::default().fg(ColorOklch::new(0.79, 0.1603, hue)),
+            color_end: TextColor::default().fg(ColorOklch::new(0.5, 0.0, hue)),
+            instant_start: now + Duration::from_secs_f32(0.25),
+            instant_end: now + Duration::from_secs_f32(1.0),
+        }))
+}
+
+/// A status message displayed while a task is in progress.
+pub fn get_status_in_progress(text: Cow<'static, str>) -> SpinnerContent<'static> {
+    get_status_in_progress_with_elapsed(text, None)
+}
+
+/// Displays a progress indicator, followed by the number of seconds elapsed since `instant_start`,
+/// if any.
+pub fn get_status_in_progress_with_elapsed(
+    text: Cow<'static, str>,
+    instant_start: Option<Instant>,
+) -> SpinnerContent<'static> {
+    SpinnerContent::default()
+        .with_text(text)
+        .with_animation(Some(Animation::ProgressIndeterminate {
+            period: Duration::from_secs_f32(0.5),
+            highlight: TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)),
+            instant_start,
+        }))
+}
+
+/// The arguments of the panes drawn at the bottom of the main view.
+pub struct PaneBottomArgs {
+    pub(super) title_offset_x: u16,
+}
+
+/// The loading state of a listed version of the opened record.
+#[derive(Debug, Clone)]
+pub enum VersionLoad {
+    /// The version was not selected yet.
+    Pending,
+    Loading,
+    Loaded(LoadedRecordVersion),
+    Failed(String),
+}
+
+/// A version of the opened record, whose content is only loaded once it is selected.
+#[derive(Debug, Clone)]
+pub struct ListedRecordVersion {
+    pub(super) record_version: RecordVersion,
+    pub(super) load: VersionLoad,
 }
 
-impl Display for Encoding {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Utf8 => write!(f, "UTF-8"),
-            Self::Hex => write!(f, "Hexadecimal Byte String"),
+impl ListedRecordVersion {
+    pub(super) fn loaded(&self) -> Option<&LoadedRecordVersion> {
+        match &self.load {
+            VersionLoad::Loaded(loaded_version) => Some(loaded_version),
+            _ => None,
         }
     }
 }
 
 #[derive(Debug, Clone)]
-struct OpenedRecord {
-    hashed_record_key: HashedRecordKey,
-    record: Arc<RecordReadVersionSuccess>, // Rc'd for cheaper cloning
+pub struct OpenedRecord {
+    pub(super) hashed_record_key: HashedRecordKey,
+    record_name: RecordName,
+    /// The record whose succession nonce was used to find this record, if any.
+    parent: Option<HashedRecordKey>,
+    /// All versions of the record in ascending order. Never empty, the latest one is loaded.
+    pub(super) versions: Vec<ListedRecordVersion>,
+    /// The index of the displayed version within `versions`. Always loaded.
+    pub(super) version_index: usize,
+    /// The index of the version to compare the displayed version against, if any. Always loaded.
+    pub(super) diff_version_index: Option<usize>,
+    /// The succession nonce of this record, derived when it was opened, so that searching for
+    /// its sub-records does not run the KDF again. Dropped when navigating away from the record.
+    /// Shared, so that cloning the state does not leave copies of the secret behind.
+    succession_nonce: Option<Arc<SuccessionNonce>>,
+}
+
+// The cached nonces are secret, so they must not outlive the last reference in memory.
+const _: () = {
+    const fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+    assert_zeroize_on_drop::<SuccessionNonce>();
+};
+
+impl OpenedRecord {
+    fn new(
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<HashedRecordKey>,
+        versions: Vec<RecordVersion>,
+        latest_version: LoadedRecordVersion,
+        succession_nonce: Option<Arc<SuccessionNonce>>,
+    ) -> Self {
+        assert_eq!(
+            versions.last(),
+            Some(&latest_version.record_version),
+            "the latest listed version must be the loaded one"
+        );
+        let mut versions = versions
+            .into_iter()
+            .map(|record_version| ListedRecordVersion {
+                record_version,
+                load: VersionLoad::Pending,
+            })
+            .collect::<Vec<_>>();
+        let version_index = versions.len() - 1;
+
+        versions[version_index].load = VersionLoad::Loaded(latest_version);
+
+        Self {
+            hashed_record_key,
+            record_name,
+            parent,
+            versions,
+            version_index,
+            diff_version_index: None,
+            succession_nonce,
+        }
+    }
+
+    fn version(&self) -> &LoadedRecordVersion {
+        self.versions[self.version_index]
+            .loaded()
+            .expect("the displayed version must be loaded")
+    }
+
+    fn record(&self) -> &RecordReadVersionSuccess {
+        &self.version().record
+    }
+
+    fn diff_version(&self) -> Option<&LoadedRecordVersion> {
+        self.diff_version_index
+            .and_then(|index| self.versions[index].loaded())
+    }
+
+    fn latest_version(&self) -> &LoadedRecordVersion {
+        self.versions
+            .last()
+            .and_then(ListedRecordVersion::loaded)
+            .expect("the latest version must be loaded")
+    }
+
+    fn is_latest_version(&self) -> bool {
+        self.version_index + 1 == self.versions.len()
+    }
+
+    /// A label for the pane titles, set if the displayed version is not the latest one.
+    fn get_version_notice(&self) -> Option<Span<'static>> {
+        (!self.is_latest_version()).then(|| {
+            Span::styled(
+                format!(
+                    " v{} of {}, not latest ",
+                    self.version().record_version,
+                    self.versions.len()
+                ),
+                TextColor::default()
+                    .fg(ColorOklch::new(0.0, 0.0, 0.0))
+                    .bg(ColorOklch::new(0.79, 0.1603, 67.76 / 360.0)),
+            )
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
-struct MainState {
-    registry: Arc<Registry<ReadLock>>,
-    opened_record: Option<OpenedRecord>,
+pub struct MainState {
+    pub(super) registry: Arc<RwLock<AnyRegistry>>,
+    /// The shards of the registry, accessible without locking the registry.
+    pub(super) shards: Arc<std::sync::Mutex<RegistryShards>>,
+    /// Whether the registry is opened for writing.
+    writable: bool,
+    /// The limits of searching for records, as last set in the open pane.
+    pub(super) search_limits: SearchConfig,
+    /// Records opened during this session, in the order they were navigated to.
+    pub(super) history: Vec<OpenedRecord>,
+    /// The index of the currently opened record within `history`.
+    history_index: usize,
+    /// Whether record names in the breadcrumb are shown in plain text.
+    breadcrumb_revealed: bool,
+    /// Identifies the latest request to open a record. Results of earlier requests are ignored.
+    open_generation: u64,
+    /// Cancels the task serving the latest request to open a record.
+    open_cancellation_token: CancellationToken,
 }
 
 impl MainState {
-    async fn get_current_succession_nonce(&self) -> SuccessionNonce {
-        if let Some(opened_record) = self.opened_record.as_ref() {
-            // This should be a pretty brief operation.
-            opened_record
-                .hashed_record_key
-                .derive_succession_nonce(&self.registry.config.kdf)
-                .await
-                .unwrap()
-        } else {
-            self.registry
-                .config
-                .kdf
-                .get_root_record_predecessor_nonce()
-                .clone()
+    /// Starts a new request to open a record, cancelling the previous one, if still running.
+    /// Returns the generation of the new request and the token cancelling it.
+    pub(super) fn begin_open_request(&mut self) -> (u64, CancellationToken) {
+        self.open_cancellation_token.cancel();
+        self.open_cancellation_token = CancellationToken::new();
+        self.open_generation += 1;
+
+        (self.open_generation, self.open_cancellation_token.clone())
+    }
+
+    /// Returns `true` iff `generation` identifies the latest request to open a record.
+    pub(super) fn is_latest_open_request(&self, generation: u64) -> bool {
+        generation == self.open_generation
+    }
+
+    pub(super) fn opened_record(&self) -> Option<&OpenedRecord> {
+        self.history.get(self.history_index)
+    }
+
+    pub(super) fn opened_record_mut(&mut self) -> Option<&mut OpenedRecord> {
+        self.history.get_mut(self.history_index)
+    }
+
+    /// Makes the record the currently opened one, discarding the records to navigate forward to.
+    fn push_history(&mut self, opened_record: OpenedRecord) {
+        if let Some(current) = self.opened_record()
+            && current.hashed_record_key == opened_record.hashed_record_key
+        {
+            self.history[self.history_index] = opened_record;
+            return;
+        }
+
+        self.drop_succession_nonce();
+        self.history.truncate(self.history_index + 1);
+        self.history.push(opened_record);
+        self.history_index = self.history.len() - 1;
+    }
+
+    /// Moves within the history by `delta` entries, returning `true` iff the opened record changed.
+    /// A running request to open a record is cancelled, so that it does not replace the record
+    /// navigated to.
+    fn navigate_history(&mut self, delta: isize) -> bool {
+        let Some(new_index) = self
+            .history_index
+            .checked_add_signed(delta)
+            .filter(|new_index| *new_index < self.history.len())
+        else {
+            return false;
+        };
+
+        self.begin_open_request();
+        self.drop_succession_nonce();
+        self.history_index = new_index;
+        true
+    }
+
+    /// Drops the succession nonce cached in the opened record, which is being navigated away from.
+    /// The nonce is derived again if the record is searched in after navigating back to it.
+    fn drop_succession_nonce(&mut self) {
+        if let Some(opened_record) = self.opened_record_mut() {
+            opened_record.succession_nonce = None;
         }
     }
+
+    /// Returns the succession nonce cached in the record `parent`, if it is opened.
+    pub(super) fn get_cached_succession_nonce(
+        &self,
+        parent: &HashedRecordKey,
+    ) -> Option<&Arc<SuccessionNonce>> {
+        self.history
+            .iter()
+            .find(|opened_record| opened_record.hashed_record_key == *parent)?
+            .succession_nonce
+            .as_ref()
+    }
+
+    /// Returns the succession nonce of the `parent` record, or the root predecessor nonce if
+    /// there is no parent. A nonce that had to be derived again is sent back to be cached.
+    pub(super) async fn get_succession_nonce(
+        &self,
+        action_tx: &UnboundedSender<Action>,
+        parent: Option<&HashedRecordKey>,
+    ) -> Result<Arc<SuccessionNonce>> {
+        if let Some(succession_nonce) =
+            parent.and_then(|parent| self.get_cached_succession_nonce(parent))
+        {
+            return Ok(succession_nonce.clone());
+        }
+
+        let registry = self.registry.read().await;
+
+        Ok(if let Some(parent) = parent {
+            // Runs the KDF, which may take long with expensive parameters.
+            let succession_nonce = Arc::new(
+                parent
+                    .derive_succession_nonce(&registry.config().kdf)
+                    .await
+                    .wrap_err("Failed to derive the succession nonce of the parent record")?,
+            );
+
+            // The UI may be shutting down, in which case there is nothing to cache the nonce in.
+            let _ = action_tx.send(Action::BroadcastMessage(
+                ComponentMessage::SuccessionNonceDerive {
+                    hashed_record_key: parent.clone(),
+                    succession_nonce: succession_nonce.clone(),
+                },
+            ));
+
+            succession_nonce
+        } else {
+            Arc::new(
+                registry
+                    .config()
+                    .kdf
+                    .get_root_record_predecessor_nonce()
+                    .clone(),
+            )
+        })
+    }
 }
 
 #[derive(Debug)]
 pub struct MainView {
     id: ComponentId,
-    args: Arc<Args>,
+    action_tx: UnboundedSender<Action>,
+    config: Arc<Config>,
     pane_open: PaneOpen,
+    pane_export: PaneExport,
+    pane_write: PaneWrite,
+    pane_settings: PaneSettings,
+    pane_tree: RecordTree,
+    pane_versions: PaneVersions,
+    pane_overview: PaneOverview,
+    metadata_tree: CborTree,
+    content_tree: CborTree,
+    /// The comparison of the displayed version against the chosen one, if any.
+    version_diff: Option<VersionDiff>,
+    scroll_content: ScrollView,
+    content_view_mode: ContentViewMode,
+    /// `content_view_mode` resolved for the displayed version.
+    resolved_content_view_mode: ContentViewMode,
+    /// The displayed version formatted in `resolved_content_view_mode`, along with the width it
+    /// was formatted for. Formatted again when drawn at a different width.
+    content_text: RefCell<Option<(u16, Text<'static>)>>,
     state: Rc<RefCell<MainState>>,
 }
 
@@ -146,33 +441,141 @@ impl MainView {
         id: ComponentId,
         tx: &UnboundedSender<Action>,
         args: &Arc<Args>,
+        config: &Arc<Config>,
     ) -> Result<Self>
     where
         Self: Sized,
     {
-        tracing::trace!(dir=?args.registry_directory);
-        let registry = Arc::new(
-            Registry::open(args.registry_directory.clone())
-                .await
-                .unwrap(),
-        );
+        tracing::trace!(dir=?config.registry_directory);
+        let registry = AnyRegistry::open(config.registry_directory.clone(), args.write).await?;
         let state = Rc::new(RefCell::new(MainState {
-            registry,
-            opened_record: None,
+            writable: registry.is_writable(),
+            search_limits: config.search,
+            shards: registry.shards().clone(),
+            registry: Arc::new(RwLock::new(registry)),
+            history: Default::default(),
+            history_index: 0,
+            breadcrumb_revealed: config.ui.reveal_breadcrumb,
+            open_generation: 0,
+            open_cancellation_token: CancellationToken::new(),
         }));
         let mut pane_open = PaneOpen::new(ComponentId::new(), tx, &state)?;
+        let pane_write = PaneWrite::new(ComponentId::new(), tx, &state);
+        let pane_versions = PaneVersions::new(ComponentId::new(), tx, &state);
+        let pane_overview = PaneOverview::new(ComponentId::new(), &state);
 
-        pane_open.spawn_open_record_task_with_record_name(RECORD_NAME_ROOT); // Attempt to open the default root record.
+        pane_open.spawn_open_record_task_with_record_name(RECORD_NAME_ROOT, None); // Attempt to open the default root record.
 
         Ok(Self {
             id,
-            args: args.clone(),
+            action_tx: tx.clone(),
+            config: config.clone(),
             state,
             pane_open,
+            pane_export: PaneExport::new(ComponentId::new(), tx),
+            pane_write,
+            pane_settings: PaneSettings::new(ComponentId::new(), tx),
+            pane_tree: RecordTree::new(ComponentId::new(), tx),
+            pane_versions,
+            pane_overview,
+            metadata_tree: CborTree::new(ComponentId::new(), tx),
+            content_tree: CborTree::new(ComponentId::new(), tx),
+            version_diff: None,
+            scroll_content: ScrollView::new(ComponentId::new()),
+            content_view_mode: config.ui.content_view_mode,
+            resolved_content_view_mode: config.ui.content_view_mode,
+            content_text: Default::default(),
         })
     }
 
-    fn pane_areas(area: Rect, title_offset_x: u16) -> (Rect, Rect) {
+    /// Panes replaced by other panes are left out of the children, so that they are neither drawn
+    /// nor focused, but they still need to receive all messages. For example, the open pane needs
+    /// to learn that a search finished while the export prompt is open.
+    fn update_hidden_panes(&mut self, message: &ComponentMessage) -> Result<()> {
+        let visible_ids = self
+            .get_children()
+            .iter()
+            .map(|child| child.get_id())
+            .collect::<Vec<_>>();
+        let action_tx = self.action_tx.clone();
+        let panes: [&mut dyn Component; 10] = [
+            &mut self.pane_open,
+            &mut self.pane_export,
+            &mut self.pane_write,
+            &mut self.pane_settings,
+            &mut self.pane_tree,
+            &mut self.metadata_tree,
+            &mut self.pane_overview,
+            &mut self.content_tree,
+            &mut self.scroll_content,
+            &mut self.pane_versions,
+        ];
+
+        for pane in panes {
+            if visible_ids.contains(&pane.get_id()) {
+                continue;
+            }
+
+            if let ControlFlow::Break(error) = component::depth_first_search_mut(
+                pane,
+                &mut |component| match component.update(message.clone()) {
+                    Ok(action) => {
+                        if let Some(action) = action {
+                            let _ = action_tx.send(action);
+                        }
+
+                        ControlFlow::Continue(())
+                    }
+                    Err(error) => ControlFlow::Break(error),
+                },
+                &mut |_| ControlFlow::Continue(()),
+            ) {
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the CBOR trees and the comparison from the displayed version of the opened record,
+    /// and resolves the content view mode for it.
+    fn rebuild_content(&mut self) {
+        *self.content_text.get_mut() = None;
+
+        let state = self.state.borrow();
+        let Some(opened_record) = state.opened_record() else {
+            self.metadata_tree.clear();
+            self.content_tree.clear();
+            self.version_diff = None;
+            return;
+        };
+        let record = opened_record.record();
+
+        self.resolved_content_view_mode = self.content_view_mode.resolve(&record.data);
+
+        // The content is only displayed as a tree if it is valid CBOR and no diff is shown.
+        let content_value = (opened_record.diff_version().is_none()
+            && self.resolved_content_view_mode == ContentViewMode::Cbor)
+            .then(|| crate::cbor::parse_cbor(&record.data))
+            .flatten();
+
+        self.metadata_tree.set_entries(
+            record
+                .metadata
+                .iter_with_semantic_keys()
+                .map(|(key, value)| (CborTreeLabel::from(key), value)),
+        );
+        self.content_tree.set_entries(
+            content_value
+                .iter()
+                .map(|value| (CborTreeLabel::None, value)),
+        );
+        self.version_diff = opened_record
+            .diff_version()
+            .map(|diff_version| VersionDiff::new(diff_version, opened_record.version()));
+    }
+
+    pub(super) fn pane_areas(area: Rect, title_offset_x: u16) -> (Rect, Rect) {
         let [mut title, content] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(1), Constraint::Fill(1)])
@@ -184,53 +587,126 @@ impl MainView {
         (title, content)
     }
 
+    /// Draws the title of a pane, highlighted while the focus is within any of the `panes`.
+    pub(super) fn draw_pane_title<'a>(
+        context: &mut DrawContext,
+        area_title: Rect,
+        title: impl Into<Line<'a>>,
+        panes: &[&dyn Component],
+    ) {
+        let mut title = title.into();
+        let focused_id = context.focused_id();
+
+        if panes
+            .iter()
+            .any(|pane| component::contains_component(*pane, focused_id))
+        {
+            title = title.patch_style(
+                Style::from(TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0))).bold(),
+            );
+        }
+
+        context.frame().render_widget(title, area_title);
+    }
+
+    /// The component displaying the record content, which is focused by the `c` mnemonic.
+    fn get_pane_content(&self) -> &dyn Component {
+        if self.pane_write.is_open() {
+            &self.pane_write
+        } else if self.content_tree.is_focusable() {
+            &self.content_tree
+        } else {
+            &self.scroll_content
+        }
+    }
+
     fn draw_header(&self, context: &mut DrawContext, area_header: Rect) -> Result<()> {
-        context.frame().render_widget(
-            Span::raw(format!("RRR TUI v{}", *PROJECT_VERSION)),
-            area_header,
-        );
+        let mut title = Line::from(format!("RRR TUI v{}", *PROJECT_VERSION));
+
+        if self.state.borrow().writable {
+            title.push_span(Span::raw(" "));
+            title.push_span(Span::styled(
+                " WRITE ",
+                TextColor::default()
+                    .fg(ColorOklch::new(0.2, 0.0, 0.0))
+                    .bg(ColorOklch::new(0.79, 0.1603, 67.76 / 360.0)),
+            ));
+        }
+
+        let shortcuts = Line::from("[S]ettings [H]elp [Q]uit");
+        let [area_title, area_breadcrumb, area_shortcuts] = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(2)
+            .constraints([
+                Constraint::Length(title.width() as u16),
+                Constraint::Fill(1),
+                Constraint::Length(shortcuts.width() as u16),
+            ])
+            .areas(area_header);
+
+        context.frame().render_widget(title, area_title);
+        context
+            .frame()
+            .render_widget(self.get_breadcrumb_line(), area_breadcrumb);
+        context.frame().render_widget(shortcuts, area_shortcuts);
         Ok(())
     }
 
+    /// The path from the top-level record to the opened record, with record names masked unless
+    /// revealed.
+    fn get_breadcrumb_line(&self) -> Line<'static> {
+        let state = self.state.borrow();
+        let Some(opened_record) = state.opened_record() else {
+            return Line::default();
+        };
+        let separator_color = TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0));
+        let labels = self
+            .pane_tree
+            .get_path_labels(&opened_record.hashed_record_key);
+        let spans = labels.into_iter().map(|(label, is_root)| {
+            // The name of the registry root record is public.
+            if state.breadcrumb_revealed || is_root {
+                Span::raw(label)
+            } else {
+                Span::raw("•••")
+            }
+        });
+        let mut line = Line::from_iter(Iterator::intersperse_with(spans, || {
+            Span::styled(" › ", &separator_color)
+        }));
+
+        if state.history.len() > 1 {
+            line.push_span(Span::styled(
+                format!("  ({}/{})", state.history_index + 1, state.history.len()),
+                &separator_color,
+            ));
+        }
+
+        line
+    }
+
     fn draw_pane_tree(&self, context: &mut DrawContext, area: Rect) -> Result<()> {
-        let (area_title, _area_content) = Self::pane_areas(area, 0);
-        context
-            .frame()
-            .render_widget(Span::raw("[T]ree"), area_title);
+        let (area_title, area_content) = Self::pane_areas(area, 0);
+        Self::draw_pane_title(context, area_title, "[T]ree", &[&self.pane_tree]);
+        self.pane_tree.draw(context, area_content, ())?;
         Ok(())
     }
 
     fn draw_pane_metadata(&self, context: &mut DrawContext, area: Rect) -> Result<()> {
         let (area_title, area_content) = Self::pane_areas(area, 0);
 
-        if let Some(opened_record) = self.state.borrow().opened_record.as_ref() {
-            let metadata_table = Table::new(
-                opened_record
-                    .record
-                    .metadata
-                    .iter_with_semantic_keys()
-                    .map(|(key, value)| crate::cbor::record_metadata_to_row(key, value)),
-                [Constraint::Length(16), Constraint::Fill(1)],
-            );
+        let mut title = Line::from("Record [M]etadata");
 
-            context.frame().render_widget(metadata_table, area_content);
+        if let Some(opened_record) = self.state.borrow().opened_record() {
+            self.metadata_tree.draw(context, area_content, ())?;
+            title.extend(opened_record.get_version_notice());
         }
 
-        context
-            .frame()
-            .render_widget(Span::raw("Record [M]etadata"), area_title);
+        Self::draw_pane_title(context, area_title, title, &[&self.metadata_tree]);
 
         Ok(())
     }
 
-    fn draw_pane_overview(&self, context: &mut DrawContext, area: Rect) -> Result<()> {
-        let (area_title, _area_content) = Self::pane_areas(area, 0);
-        context
-            .frame()
-            .render_widget(Span::raw("[O]verview"), area_title);
-        Ok(())
-    }
-
     fn draw_pane_content(
         &self,
         context: &mut DrawContext,
@@ -238,21 +714,68 @@ impl MainView {
         title_offset_x: u16,
     ) -> Result<()> {
         let (area_title, area_content) = Self::pane_areas(area, title_offset_x);
+        let mut title = Line::from("Record [C]ontent");
+
+        if let Some(opened_record) = self.state.borrow().opened_record() {
+            if let Some(diff_version) = opened_record.diff_version() {
+                title.push_span(Span::raw(format!(
+                    " (diff v{} → v{}, [Esc] to close)",
+                    diff_version.record_version,
+                    opened_record.version().record_version
+                )));
+            } else {
+                let resolved_mode = self.resolved_content_view_mode;
+
+                title.push_span(Span::raw(
+                    if self.content_view_mode == ContentViewMode::Auto {
+                        format!(" [F]ormat: {resolved_mode} (auto)")
+                    } else {
+                        format!(" [F]ormat: {resolved_mode}")
+                    },
+                ));
+                title.push_span(Span::raw(" [E]xport… [Y]ank"));
+
+                if self.state.borrow().writable {
+                    title.push_span(Span::raw(" [N]ew [A]ppend"));
+                }
 
-        context
-            .frame()
-            .render_widget(Span::raw("Record [C]ontent"), area_title);
+                title.extend(opened_record.get_version_notice());
+            }
+        }
 
-        if let Some(opened_record) = self.state.borrow().opened_record.as_ref() {
-            let data_string = String::from_utf8_lossy(&opened_record.record.data);
-            let lines = textwrap::wrap(
-                data_string.as_ref(),
-                textwrap::Options::new(area.width as usize),
-            );
-            context.frame().render_widget(
-                Text::from_iter(lines).style(Style::default()), // TODO: Other formats
-                area_content,
-            );
+        Self::draw_pane_title(
+            context,
+            area_title,
+            title,
+            &[&self.content_tree, &self.scroll_content],
+        );
+
+        if let Some(opened_record) = self.state.borrow().opened_record() {
+            if let Some(version_diff) = &self.version_diff {
+                return self.scroll_content.draw(
+                    context,
+                    area_content,
+                    ScrollViewArgs::new(version_diff),
+                );
+            }
+
+            if self.content_tree.is_focusable() {
+                return self.content_tree.draw(context, area_content, ());
+            }
+
+            let width = ScrollView::get_content_width(area_content);
+            let mut content_text = self.content_text.borrow_mut();
+            let (_, text) = match &mut *content_text {
+                Some(cached) if cached.0 == width => cached,
+                cached => cached.insert((
+                    width,
+                    self.resolved_content_view_mode
+                        .format(&opened_record.record().data, width),
+                )),
+            };
+
+            self.scroll_content
+                .draw(context, area_content, ScrollViewArgs::new(&*text))?;
         }
 
         Ok(())
@@ -260,32 +783,340 @@ impl MainView {
 }
 
 impl Component for MainView {
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('f'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                self.content_view_mode = self.content_view_mode.next();
+                self.scroll_content.scroll_to_top();
+                self.rebuild_content();
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('e'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                let version = self
+                    .state
+                    .borrow()
+                    .opened_record()
+                    .map(|opened_record| opened_record.version().clone());
+                let Some(version) = version else {
+                    return Ok(HandleEventSuccess::unhandled());
+                };
+
+                Ok(HandleEventSuccess::handled().with_action(self.pane_export.open(version)))
+            }
+            Event::Key(KeyEvent {
+                code: code @ KeyCode::Char('y' | 'Y'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+                ..
+            }) => {
+                let state = self.state.borrow();
+                let Some(opened_record) = state.opened_record() else {
+                    return Ok(HandleEventSuccess::unhandled());
+                };
+                // Shift copies the hashed record key instead of the data.
+                let content = if *code == KeyCode::Char('Y') {
+                    format!(
+                        "{:02x}",
+                        opened_record.hashed_record_key.as_bytes().iter().format("")
+                    )
+                } else {
+                    let data = &opened_record.record().data;
+
+                    match std::str::from_utf8(data) {
+                        Ok(text) => text.to_string(),
+                        Err(_) => format!("{:02x}", data.iter().format("")),
+                    }
+                };
+
+                Ok(HandleEventSuccess::handled().with_action(Action::CopyToClipboard(content)))
+            }
+            Event::Key(KeyEvent {
+                code: code @ KeyCode::Char('n' | 'a'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                let state = self.state.borrow();
+
+                if !state.writable {
+                    return Ok(HandleEventSuccess::handled().with_action(
+                        Action::BroadcastMessage(ComponentMessage::ShowError {
+                            error: "Read-only, restart with --write".into(),
+                            open_generation: None,
+                        }),
+                    ));
+                }
+
+                let opened_record = state.opened_record();
+                let target = if *code == KeyCode::Char('n') {
+                    WriteTarget::SubRecord {
+                        parent: opened_record
+                            .map(|opened_record| opened_record.hashed_record_key.clone()),
+                    }
+                } else {
+                    let Some(opened_record) = opened_record else {
+                        return Ok(HandleEventSuccess::unhandled());
+                    };
+
+                    WriteTarget::Version {
+                        hashed_record_key: opened_record.hashed_record_key.clone(),
+                        record_name: opened_record.record_name.clone(),
+                        parent: opened_record.parent.clone(),
+                        latest_version: opened_record.latest_version().clone(),
+                    }
+                };
+
+                drop(state);
+                Ok(HandleEventSuccess::handled().with_action(self.pane_write.open(target)))
+            }
+            // Mnemonics are only received if the focused component, such as an `InputField`,
+            // does not handle the key itself.
+            Event::Key(KeyEvent {
+                code: KeyCode::Char(mnemonic @ ('t' | 'm' | 'o' | 'c' | 'v')),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                let pane: &dyn Component = match mnemonic {
+                    't' => &self.pane_tree,
+                    'm' => &self.metadata_tree,
+                    'o' => &self.pane_overview,
+                    'c' => self.get_pane_content(),
+                    _ => &self.pane_versions,
+                };
+                let Some(focus_target) = component::find_first_focusable(pane) else {
+                    return Ok(HandleEventSuccess::unhandled());
+                };
+
+                Ok(HandleEventSuccess::handled()
+                    .with_action(Action::FocusComponent(focus_target.get_id())))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('s'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => Ok(
+                HandleEventSuccess::handled().with_action(self.pane_settings.open(&self.config))
+            ),
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('h'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => Ok(HandleEventSuccess::handled().with_action(Action::ToggleHelp)),
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => Ok(HandleEventSuccess::handled().with_action(Action::Quit)),
+            _ => Ok(HandleEventSuccess::unhandled()),
+        }
+    }
+
     fn update(&mut self, message: ComponentMessage) -> Result<Option<crate::action::Action>> {
+        self.update_hidden_panes(&message)?;
+
         match message {
             ComponentMessage::RecordOpen {
+                generation,
                 hashed_record_key,
-                read_result: Some(read_result),
-            } => {
-                self.state.borrow_mut().opened_record = Some(OpenedRecord {
+                record_name,
+                parent,
+                versions,
+                latest_version: Some(latest_version),
+                succession_nonce,
+                ..
+            } if self.state.borrow().is_latest_open_request(generation) => {
+                self.pane_tree.insert_opened(
+                    hashed_record_key.clone(),
+                    record_name.clone(),
+                    parent.as_ref(),
+                );
+                self.state.borrow_mut().push_history(OpenedRecord::new(
                     hashed_record_key,
-                    record: Arc::new(read_result),
-                });
+                    record_name,
+                    parent,
+                    versions,
+                    latest_version,
+                    succession_nonce,
+                ));
+                self.scroll_content.scroll_to_top();
+                self.rebuild_content();
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::Navigate(navigation @ (Navigation::Back | Navigation::Forward)) => {
+                let mut state = self.state.borrow_mut();
+                let delta = if navigation == Navigation::Back {
+                    -1
+                } else {
+                    1
+                };
+
+                if !state.navigate_history(delta) {
+                    return Ok(None);
+                }
+
+                if let Some(opened_record) = state.opened_record() {
+                    self.pane_tree.set_opened(&opened_record.hashed_record_key);
+                }
+
+                drop(state);
+                self.scroll_content.scroll_to_top();
+                self.rebuild_content();
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::SuccessionNonceDerive {
+                hashed_record_key,
+                succession_nonce,
+            } => {
+                // Only the opened record keeps its nonce, see `OpenedRecord::succession_nonce`.
+                let mut state = self.state.borrow_mut();
+
+                if let Some(opened_record) = state.opened_record_mut()
+                    && opened_record.hashed_record_key == hashed_record_key
+                {
+                    opened_record.succession_nonce = Some(succession_nonce);
+                }
+
+                Ok(None)
+            }
+            ComponentMessage::DisplayedVersionChange => {
+                self.scroll_content.scroll_to_top();
+                self.rebuild_content();
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::ToggleBreadcrumbMask => {
+                let mut state = self.state.borrow_mut();
+                state.breadcrumb_revealed = !state.breadcrumb_revealed;
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::ConfigChange(config) => {
+                // Initial values are only applied if they were changed.
+                if config.ui.reveal_breadcrumb != self.config.ui.reveal_breadcrumb {
+                    self.state.borrow_mut().breadcrumb_revealed = config.ui.reveal_breadcrumb;
+                }
+
+                if config.ui.content_view_mode != self.config.ui.content_view_mode {
+                    self.content_view_mode = config.ui.content_view_mode;
+                    self.scroll_content.scroll_to_top();
+                    self.rebuild_content();
+                }
+
+                self.config = config;
                 Ok(Some(Action::Render))
             }
             _ => Ok(None),
         }
     }
 
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        let mut key_hints = KeyHints::new("Main View")
+            .with_hint(
+                "<t> <m> <o> <c> <v>",
+                "Focus the tree, metadata, overview, content or versions",
+            )
+            .with_hint("<f>", "Change the format of the content")
+            .with_hint("<e>", "Export the displayed version")
+            .with_hint("<y>", "Copy the content")
+            .with_hint("<Y>", "Copy the hashed record key");
+
+        if self.state.borrow().writable {
+            key_hints = key_hints
+                .with_hint("<n>", "Create a new sub-record")
+                .with_hint("<a>", "Append a new version");
+        }
+
+        Some(
+            key_hints
+                .with_hint("<s>", "Show the settings")
+                .with_hint("<h>", "Show this help")
+                .with_hint("<q>", "Quit the application"),
+        )
+    }
+
     fn get_id(&self) -> ComponentId {
         self.id
     }
 
     fn get_children(&self) -> Vec<&dyn Component> {
-        vec![&self.pane_open]
+        // The settings take the place of all panes while they are open.
+        if self.pane_settings.is_open() {
+            return vec![&self.pane_settings];
+        }
+
+        // The export prompt takes the place of the open pane while it is open.
+        let pane_bottom: &dyn Component = if self.pane_export.is_open() {
+            &self.pane_export
+        } else {
+            &self.pane_open
+        };
+
+        // The write form takes the place of the content pane while it is open.
+        if self.pane_write.is_open() {
+            return vec![
+                pane_bottom,
+                &self.pane_tree,
+                &self.metadata_tree,
+                &self.pane_overview,
+                &self.pane_write,
+                &self.pane_versions,
+            ];
+        }
+
+        vec![
+            pane_bottom,
+            &self.pane_tree,
+            &self.metadata_tree,
+            &self.pane_overview,
+            &self.content_tree,
+            &self.scroll_content,
+            &self.pane_versions,
+        ]
     }
 
     fn get_children_mut(&mut self) -> Vec<&mut dyn Component> {
-        vec![&mut self.pane_open]
+        if self.pane_settings.is_open() {
+            return vec![&mut self.pane_settings];
+        }
+
+        let pane_bottom: &mut dyn Component = if self.pane_export.is_open() {
+            &mut self.pane_export
+        } else {
+            &mut self.pane_open
+        };
+
+        if self.pane_write.is_open() {
+            return vec![
+                pane_bottom,
+                &mut self.pane_tree,
+                &mut self.metadata_tree,
+                &mut self.pane_overview,
+                &mut self.pane_write,
+                &mut self.pane_versions,
+            ];
+        }
+
+        vec![
+            pane_bottom,
+            &mut self.pane_tree,
+            &mut self.metadata_tree,
+            &mut self.pane_overview,
+            &mut self.content_tree,
+            &mut self.scroll_content,
+            &mut self.pane_versions,
+        ]
     }
 
     fn get_accessibility_node(&self) -> Result<accesskit::Node> {
@@ -320,52 +1151,79 @@ impl Drawable for MainView {
             merged: symbols::line::HORIZONTAL,
         };
 
-        if let Some(force_max_width) = self.args.force_max_width.as_ref() {
-            area.width = std::cmp::min(area.width, *force_max_width);
+        if let Some(max_width) = self.config.ui.max_width {
+            area.width = std::cmp::min(area.width, max_width);
         }
 
-        if let Some(force_max_height) = self.args.force_max_height.as_ref() {
-            area.height = std::cmp::min(area.height, *force_max_height);
+        if let Some(max_height) = self.config.ui.max_height {
+            area.height = std::cmp::min(area.height, max_height);
         }
 
+        context.record_area(self.id, area);
         context
             .frame()
             .buffer_mut()
             .set_style(area, TextColor::default());
 
-        let [
-            area_header,
-            area_top,
-            area_content,
-            area_bottom,
-            area_footer,
-        ] = Layout::default()
+        let [area_header, area_top, area_middle, area_bottom, area_footer] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(1),
                 Constraint::Length(7),
                 Constraint::Fill(1),
-                Constraint::Length(3),
+                Constraint::Length(if self.pane_export.is_open() {
+                    3
+                } else {
+                    self.pane_open.get_height()
+                }),
                 Constraint::Length(1),
             ])
             .areas(area);
+
+        if self.pane_settings.is_open() {
+            let area_settings = Rect {
+                height: area_bottom.bottom() - area_top.y,
+                ..area_top
+            };
+
+            context.frame().render_widget(
+                SPACER_HORIZONTAL.clone(),
+                Rect {
+                    height: 1,
+                    ..area_settings
+                },
+            );
+            context
+                .frame()
+                .render_widget(SPACER_HORIZONTAL.clone(), area_footer);
+            self.pane_settings.draw(context, area_settings, ())?;
+            self.draw_header(context, area_header)?;
+            return Ok(());
+        }
+
         let layout_top = Layout::default()
             .direction(Direction::Horizontal)
             .spacing(1)
             .constraints([
-                Constraint::Length(8),
+                Constraint::Length(16),
                 Constraint::Fill(1),
                 Constraint::Length(16),
             ]);
         let [area_tree, area_metadata, area_overview] = layout_top.areas(area_top);
         let [_, area_top_spacer_0, area_top_spacer_1, _] = layout_top.spacers(area_top);
+        let layout_content = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([Constraint::Fill(1), Constraint::Length(24)]);
+        let [area_content, area_versions] = layout_content.areas(area_middle);
+        let [_, area_content_spacer, _] = layout_content.spacers(area_middle);
 
         context
             .frame()
             .render_widget(SPACER_HORIZONTAL.clone(), area_top);
         context
             .frame()
-            .render_widget(SPACER_HORIZONTAL.clone(), area_content);
+            .render_widget(SPACER_HORIZONTAL.clone(), area_middle);
         context
             .frame()
             .render_widget(SPACER_HORIZONTAL.clone(), area_bottom);
@@ -386,18 +1244,46 @@ impl Drawable for MainView {
                 ..area_top_spacer_1
             },
         );
+        context.frame().render_widget(
+            SPACER_VERTICAL_FORKED.clone(),
+            Rect {
+                height: area_content_spacer.height + 1,
+                ..area_content_spacer
+            },
+        );
 
         self.draw_pane_tree(context, area_tree)?;
         self.draw_pane_metadata(context, area_metadata)?;
-        self.draw_pane_overview(context, area_overview)?;
-        self.draw_pane_content(context, area_content, area_metadata.x)?;
-        self.pane_open.draw(
-            context,
-            area_bottom,
-            PaneOpenArgs {
-                title_offset_x: area_metadata.x,
-            },
-        )?;
+        self.pane_overview.draw(context, area_overview, ())?;
+        if self.pane_write.is_open() {
+            self.pane_write.draw(
+                context,
+                area_content,
+                PaneBottomArgs {
+                    title_offset_x: area_metadata.x,
+                },
+            )?;
+        } else {
+            self.draw_pane_content(context, area_content, area_metadata.x)?;
+        }
+        self.pane_versions.draw(context, area_versions, ())?;
+        if self.pane_export.is_open() {
+            self.pane_export.draw(
+                context,
+                area_bottom,
+                PaneBottomArgs {
+                    title_offset_x: area_metadata.x,
+                },
+            )?;
+        } else {
+            self.pane_open.draw(
+                context,
+                area_bottom,
+                PaneBottomArgs {
+                    title_offset_x: area_metadata.x,
+                },
+            )?;
+        }
         self.draw_header(context, area_header)?;
 
         /* Debug Oklch color space
@@ -419,275 +1305,24 @@ impl Drawable for MainView {
     }
 }
 
-#[derive(Debug)]
-struct PaneOpen {
-    id: ComponentId,
-    action_tx: UnboundedSender<Action>,
-    main_state: Rc<RefCell<MainState>>,
-    record_name_field: InputField,
-    encoding_radio_array: RadioArray<Encoding>,
-    status_spinner: OpenStatus<'static>,
-    button: Button,
-}
-
-impl PaneOpen {
-    pub fn new(
-        id: ComponentId,
-        action_tx: &UnboundedSender<Action>,
-        main_state: &Rc<RefCell<MainState>>,
-    ) -> Result<Self> {
-        Ok(Self {
-            id,
-            action_tx: action_tx.clone(),
-            main_state: main_state.clone(),
-            record_name_field: InputField::new(ComponentId::new(), action_tx),
-            encoding_radio_array: RadioArray::new(
-                ComponentId::new(),
-                action_tx,
-                vec![Encoding::Utf8, Encoding::Hex],
-                &Encoding::Utf8,
-                Direction::Horizontal,
-            ),
-            status_spinner: OpenStatus::new(
-                ComponentId::new(),
-                action_tx,
-                SpinnerContent::default(),
-            ),
-            button: Button::new(ComponentId::new(), action_tx, "Search".into())
-                .with_form_confirmation(true)
-                .with_text_color_unfocused(TextColor::default().bg(ColorOklch::new(0.2, 0.0, 0.0)))
-                .with_text_color_focused(TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)))
-                .with_text_color_pressed(TextColor::default().bg(ColorOklch::new(0.3, 0.0, 0.0))),
-        })
-    }
-
-    fn get_record_name(&self) -> RecordName {
-        match self.encoding_radio_array.get_checked() {
-            Encoding::Utf8 => BytesOrAscii(self.record_name_field.get_content().as_bytes().into()),
-            Encoding::Hex => todo!(),
-        }
-    }
-
-    fn spawn_open_record_task(&mut self) {
-        let record_name = self.get_record_name();
-        self.spawn_open_record_task_with_record_name(record_name);
-    }
-
-    fn spawn_open_record_task_with_record_name(&mut self, record_name: RecordName) {
-        // The main state is being cloned just because `MainState::get_current_succession_nonce`
-        // is an async function that needs to be awaited from within an async block.
-        // If this function ever becomes async, it should be moved up out of the async task.
-        let main_state_clone = self.main_state.borrow().clone();
-        let action_tx = self.action_tx.clone();
-
-        self.status_spinner.content = SpinnerContent::default()
-            .with_text(" Searching… ".into())
-            .with_animation(Some(Animation::ProgressIndeterminate {
-                period: Duration::from_secs_f32(0.5),
-                highlight: TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)),
-            }));
-
-        tokio::spawn(
-            async move {
-                let registry = &*main_state_clone.registry;
-                let current_succession_nonce =
-                    main_state_clone.get_current_succession_nonce().await;
-                let record_key = RecordKey {
-                    predecessor_nonce: current_succession_nonce,
-                    record_name,
-                };
-
-                error::report(&action_tx.clone(), async move || {
-                    let (hashed_record_key, read_result) =
-                        Self::open_record(record_key, registry).await?;
-
-                    debug!(?read_result, "Sending read result.");
-
-                    action_tx.send(Action::BroadcastMessage(ComponentMessage::RecordOpen {
-                        hashed_record_key,
-                        read_result,
-                    }))?;
-
-                    Ok(())
-                })
-                .await;
-            }
-            .instrument(info_span!("open record task")),
-        );
-    }
-
-    async fn open_record(
-        record_key: RecordKey,
-        registry: &Registry<ReadLock>,
-    ) -> Result<(HashedRecordKey, Option<RecordReadVersionSuccess>)> {
-        let hashed_record_key = record_key.hash(&registry.config.hash).await?;
-        let versions = registry
-            .list_record_versions(&hashed_record_key, 4, 4)
-            .await?;
-        let Some(latest_version) = versions.last() else {
-            return Ok((hashed_record_key, None));
-        };
-        let record = registry
-            .load_record(&hashed_record_key, latest_version.record_version, 4)
-            .await?
-            .ok_or_else(|| eyre!("Failed to load the latest root record version."))?;
-        Ok((hashed_record_key, Some(record)))
-    }
-}
-
-impl Component for PaneOpen {
-    fn update(&mut self, message: ComponentMessage) -> Result<Option<Action>> {
-        match message {
-            ComponentMessage::RecordOpen { read_result, .. } => {
-                let now = Instant::now();
-                if read_result.is_some() {
-                    self.record_name_field.reset_content();
-                    self.status_spinner.content = SpinnerContent::default()
-                        .with_text("Record found".into())
-                        .with_animation(Some(Animation::Ease {
-                            easing_function: easing_function::easings::EaseInOutCubic.into(),
-                            color_start: TextColor::default().fg(ColorOklch::new(
-                                0.79,
-                                0.1603,
-                                153.29 / 360.0,
-                            )),
-                            color_end: TextColor::default().fg(ColorOklch::new(
-                                0.5,
-                                0.0,
-                                153.29 / 360.0,
-                            )),
-                            instant_start: now + Duration::from_secs_f32(0.25),
-                            instant_end: now + Duration::from_secs_f32(1.0),
-                        }));
-                } else {
-                    self.status_spinner.content = SpinnerContent::default()
-                        .with_text("Record not found".into())
-                        .with_animation(Some(Animation::Ease {
-                            easing_function: easing_function::easings::EaseInOutCubic.into(),
-                            color_start: TextColor::default().fg(ColorOklch::new(
-                                0.79,
-                                0.1603,
-                                67.76 / 360.0,
-                            )),
-                            color_end: TextColor::default().fg(ColorOklch::new(
-                                0.5,
-                                0.0,
-                                67.76 / 360.0,
-                            )),
-                            instant_start: now + Duration::from_secs_f32(0.25),
-                            instant_end: now + Duration::from_secs_f32(1.0),
-                        }));
-                }
-
-                Ok(Some(Action::Render))
-            }
-            _ => Ok(None),
-        }
-    }
-
-    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
-        match event {
-            Event::Key(KeyEvent {
-                code: KeyCode::Enter,
-                kind: KeyEventKind::Press,
-                modifiers: KeyModifiers::NONE,
-                ..
-            }) => {
-                self.spawn_open_record_task();
-                self.button.held_down = true;
-                Ok(HandleEventSuccess::handled().with_action(Action::Render))
-            }
-            Event::Key(KeyEvent {
-                code: KeyCode::Enter,
-                kind: KeyEventKind::Release,
-                ..
-            })
-            | Event::FocusLost => {
-                self.button.held_down = false;
-                Ok(HandleEventSuccess::handled().with_action(Action::Render))
-            }
-            _ => Ok(HandleEventSuccess::unhandled()),
-        }
-    }
-
-    fn get_id(&self) -> ComponentId {
-        self.id
-    }
-
-    fn get_children(&self) -> Vec<&dyn Component> {
-        vec![
-            &self.record_name_field,
-            &self.encoding_radio_array,
-            &self.status_spinner,
-            &self.button,
-        ]
-    }
-
-    fn get_children_mut(&mut self) -> Vec<&mut dyn Component> {
-        vec![
-            &mut self.record_name_field,
-            &mut self.encoding_radio_array,
-            &mut self.status_spinner,
-            &mut self.button,
-        ]
-    }
-}
-
-struct PaneOpenArgs {
-    title_offset_x: u16,
-}
-
-impl Drawable for PaneOpen {
-    type Args<'a>
-        = PaneOpenArgs
-    where
-        Self: 'a;
-
-    fn draw<'a>(
-        &self,
-        context: &mut DrawContext,
-        area: Rect,
-        extra_args: Self::Args<'a>,
-    ) -> Result<()>
-    where
-        Self: 'a,
-    {
-        let (area_title, area_content) = MainView::pane_areas(area, extra_args.title_offset_x);
-
-        context
-            .frame()
-            .render_widget(Span::raw("Open Sub-Record [Enter]"), area_title);
-
-        let layout_bottom_lines = Layout::default()
-            .direction(Direction::Horizontal)
-            .spacing(1)
-            .constraints([
-                Constraint::Length(11),
-                Constraint::Fill(1),
-                Constraint::Length(18),
-            ]);
-        let [area_record_name, area_encoding] = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Length(1)])
-            .areas(area_content);
-        let [area_record_name_label, area_record_name_field, area_status] =
-            layout_bottom_lines.areas(area_record_name);
-        let [area_encoding_label, area_encoding_field, area_button] =
-            layout_bottom_lines.areas(area_encoding);
-
-        context
-            .frame()
-            .render_widget(Span::raw("Record Name"), area_record_name_label);
-        self.record_name_field
-            .draw(context, area_record_name_field, ())?;
-        self.status_spinner.draw(context, area_status, ())?;
-        context
-            .frame()
-            .render_widget(Span::raw("Encoding"), area_encoding_label);
-        self.encoding_radio_array
-            .draw(context, area_encoding_field, ())?;
-        self.button.draw(context, area_button, ())?;
-
-        Ok(())
-    }
+/// Loads the content of a single version of the record.
+pub async fn load_record_version(
+    registry: &AnyRegistry,
+    hashed_record_key: &HashedRecordKey,
+    record_version: RecordVersion,
+    search_limits: SearchConfig,
+) -> Result<LoadedRecordVersion> {
+    let record = registry
+        .load_record(
+            hashed_record_key,
+            record_version,
+            search_limits.max_collision_resolution_attempts,
+        )
+        .await?
+        .ok_or_else(|| eyre!("Failed to load version {record_version} of the record."))?;
+
+    Ok(LoadedRecordVersion {
+        record_version,
+        record: Arc::new(record),
+    })
 }
@@ -1,6 +1,20 @@
 pub mod button;
+pub mod cbor_tree;
 pub mod checkbox;
+pub mod content_view_mode;
+pub mod encoding;
+pub mod help_overlay;
 pub mod input_field;
 pub mod main_view;
 pub mod open_status;
+pub mod pane_export;
+pub mod pane_open;
+pub mod pane_overview;
+pub mod pane_settings;
+pub mod pane_versions;
+pub mod pane_write;
 pub mod radio_array;
+pub mod record_tree;
+pub mod scroll_view;
+pub mod shard_map;
+pub mod version_diff;
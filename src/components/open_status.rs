@@ -15,9 +15,12 @@ use crate::rect::{LineAlignment, PlaneAlignment, RectExt};
 
 #[derive(Debug)]
 pub enum Animation {
+    /// Moves a highlight back and forth. If `instant_start` is set, the number of seconds elapsed
+    /// since then is appended to the text.
     ProgressIndeterminate {
         period: Duration,
         highlight: TextColor,
+        instant_start: Option<Instant>,
     },
     Ease {
         easing_function: EasingFunction,
@@ -26,12 +29,22 @@ pub enum Animation {
         color_start: TextColor,
         color_end: TextColor,
     },
+    /// Appends the number of seconds remaining until `instant_end` to the text, while fading
+    /// from `color_start` to `color_end`.
+    Countdown {
+        instant_start: Instant,
+        instant_end: Instant,
+        color_start: TextColor,
+        color_end: TextColor,
+    },
 }
 
 impl Animation {
     fn apply(&self, context: &mut DrawContext, area: Rect) {
         match self {
-            Animation::ProgressIndeterminate { period, highlight } => {
+            Animation::ProgressIndeterminate {
+                period, highlight, ..
+            } => {
                 let cos = (context.elapsed_time().as_secs_f32() * std::f32::consts::TAU
                     / period.as_secs_f32())
                 .cos();
@@ -65,6 +78,37 @@ impl Animation {
 
                 context.frame().buffer_mut().set_style(area, style);
             }
+            Animation::Countdown {
+                instant_start,
+                instant_end,
+                color_start,
+                color_end,
+            } => {
+                let period = instant_end.duration_since(*instant_start).as_secs_f32();
+                let elapsed = context.now().duration_since(*instant_start).as_secs_f32();
+                let normalized = (elapsed / period).clamp(0.0, 1.0);
+                let style = Lerp::lerp(color_start, color_end, normalized);
+
+                context.frame().buffer_mut().set_style(area, style);
+            }
+        }
+    }
+
+    /// Text to be appended to the spinner text at the instant `now`.
+    fn get_text_suffix(&self, now: Instant) -> Option<String> {
+        match self {
+            Animation::ProgressIndeterminate {
+                instant_start: Some(instant_start),
+                ..
+            } => {
+                let elapsed = now.saturating_duration_since(*instant_start);
+                Some(format!(" {}s ", elapsed.as_secs()))
+            }
+            Animation::Countdown { instant_end, .. } => {
+                let remaining = instant_end.saturating_duration_since(now);
+                Some(format!(" {}s left", remaining.as_secs_f32().ceil() as u64))
+            }
+            _ => None,
         }
     }
 }
@@ -138,7 +182,16 @@ impl<'a> Drawable for OpenStatus<'a> {
         Self: 'b,
     {
         area = area.without_padding(self.content.padding);
-        let line = Span::styled(self.content.text.as_ref(), &self.content.color);
+        let text = match self
+            .content
+            .animation
+            .as_ref()
+            .and_then(|animation| animation.get_text_suffix(context.now()))
+        {
+            Some(suffix) => Cow::Owned(format!("{}{suffix}", self.content.text)),
+            None => Cow::Borrowed(self.content.text.as_ref()),
+        };
+        let line = Span::styled(text, &self.content.color);
         let width = line.width() as u16;
         area = area.align(
             Size::new(width, 1),
@@ -148,6 +201,7 @@ impl<'a> Drawable for OpenStatus<'a> {
             },
         );
 
+        context.record_area(self.id, area);
         context.frame().render_widget(line, area);
 
         if let Some(animation) = self.content.animation.as_ref() {
@@ -0,0 +1,399 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use rrr::record::RecordMetadata;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{Instrument, info_span};
+
+use crate::action::{Action, ComponentMessage, LoadedRecordVersion};
+use crate::color::{ColorOklch, TextColor};
+use crate::component::{
+    Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints,
+};
+use crate::tui::Event;
+
+use super::button::Button;
+use super::input_field::InputField;
+use super::main_view::{MainView, PaneBottomArgs, get_status_finished, get_status_in_progress};
+use super::open_status::{OpenStatus, SpinnerContent};
+use super::radio_array::RadioArray;
+
+/// The format in which the metadata is exported alongside the record data, if at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetadataExportFormat {
+    None,
+    Cbor,
+    Json,
+}
+
+impl Display for MetadataExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Cbor => write!(f, "CBOR"),
+            Self::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+impl MetadataExportFormat {
+    /// The path of the metadata file, derived from the path of the data file.
+    fn get_metadata_path(self, data_path: &Path) -> Option<PathBuf> {
+        let extension = match self {
+            Self::None => return None,
+            Self::Cbor => "metadata.cbor",
+            Self::Json => "metadata.json",
+        };
+        let mut metadata_path = data_path.as_os_str().to_owned();
+
+        metadata_path.push(".");
+        metadata_path.push(extension);
+        Some(metadata_path.into())
+    }
+
+    fn encode(self, metadata: &RecordMetadata) -> Result<Vec<u8>> {
+        let value = crate::cbor::record_metadata_to_cbor_value(metadata);
+
+        match self {
+            Self::None => Ok(Vec::new()),
+            Self::Cbor => {
+                let mut encoded = Vec::new();
+                ciborium::into_writer(&value, &mut encoded)?;
+                Ok(encoded)
+            }
+            Self::Json => Ok(serde_json::to_vec_pretty(
+                &crate::cbor::cbor_value_to_json(&value),
+            )?),
+        }
+    }
+}
+
+/// Prompts for a path to export a record version to, replacing the open pane while open.
+#[derive(Debug)]
+pub struct PaneExport {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    /// The version to export, set while the prompt is open.
+    version: Option<LoadedRecordVersion>,
+    path_field: InputField,
+    metadata_radio_array: RadioArray<MetadataExportFormat>,
+    status_spinner: OpenStatus<'static>,
+    button: Button,
+    /// The export the user has been asked to confirm overwriting existing files for.
+    overwrite_prompted: Option<(PathBuf, MetadataExportFormat)>,
+}
+
+impl PaneExport {
+    pub fn new(id: ComponentId, action_tx: &UnboundedSender<Action>) -> Self {
+        Self {
+            id,
+            action_tx: action_tx.clone(),
+            version: None,
+            path_field: InputField::new(ComponentId::new(), action_tx),
+            metadata_radio_array: RadioArray::new(
+                ComponentId::new(),
+                action_tx,
+                vec![
+                    MetadataExportFormat::None,
+                    MetadataExportFormat::Cbor,
+                    MetadataExportFormat::Json,
+                ],
+                &MetadataExportFormat::None,
+                Direction::Horizontal,
+            ),
+            status_spinner: OpenStatus::new(
+                ComponentId::new(),
+                action_tx,
+                SpinnerContent::default(),
+            ),
+            button: Button::new(ComponentId::new(), action_tx, "Export".into())
+                .with_form_confirmation(true)
+                .with_text_color_unfocused(TextColor::default().bg(ColorOklch::new(0.2, 0.0, 0.0)))
+                .with_text_color_focused(TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)))
+                .with_text_color_pressed(TextColor::default().bg(ColorOklch::new(0.3, 0.0, 0.0))),
+            overwrite_prompted: None,
+        }
+    }
+
+    pub(super) fn is_open(&self) -> bool {
+        self.version.is_some()
+    }
+
+    /// Opens the prompt for exporting `version`, returning the action focusing the path field.
+    pub(super) fn open(&mut self, version: LoadedRecordVersion) -> Action {
+        self.version = Some(version);
+        self.status_spinner.content = SpinnerContent::default();
+        self.overwrite_prompted = None;
+        Action::FocusComponent(self.path_field.get_id())
+    }
+
+    fn close(&mut self) {
+        self.version = None;
+        self.button.held_down = false;
+    }
+
+    /// The entered path, with a leading `~` expanded to the home directory.
+    fn get_path(&self) -> Result<PathBuf> {
+        let input = self.path_field.get_content().trim();
+
+        if input.is_empty() {
+            return Err(eyre!("Enter a path"));
+        }
+
+        if let Some(relative) = input.strip_prefix("~/")
+            && let Some(base_dirs) = directories::BaseDirs::new()
+        {
+            return Ok(base_dirs.home_dir().join(relative));
+        }
+
+        Ok(input.into())
+    }
+
+    fn spawn_export_task(&mut self) {
+        let Some(version) = self.version.clone() else {
+            return;
+        };
+        let metadata_format = *self.metadata_radio_array.get_checked();
+        let data_path = match self.get_path() {
+            Ok(data_path) => data_path,
+            Err(error) => {
+                self.status_spinner.content =
+                    get_status_finished(error.to_string().into(), 29.23 / 360.0);
+                return;
+            }
+        };
+        let metadata_path = metadata_format.get_metadata_path(&data_path);
+        let any_file_exists = std::iter::once(&data_path)
+            .chain(&metadata_path)
+            .any(|path| path.exists());
+        let export = (data_path.clone(), metadata_format);
+
+        // Existing files are only overwritten after the user submits the same export again.
+        let overwrite = self.overwrite_prompted.as_ref() == Some(&export);
+
+        if any_file_exists && !overwrite {
+            self.overwrite_prompted = Some(export);
+            self.status_spinner.content =
+                get_status_finished("Overwrite? [Enter]".into(), 67.76 / 360.0);
+            return;
+        }
+
+        let action_tx = self.action_tx.clone();
+
+        self.overwrite_prompted = None;
+        self.status_spinner.content = get_status_in_progress(" Exporting… ".into());
+
+        tokio::spawn(
+            async move {
+                let result = async {
+                    write_private_file(&data_path, &version.record.data, overwrite).await?;
+
+                    if let Some(metadata_path) = metadata_path {
+                        let metadata = metadata_format.encode(&version.record.metadata)?;
+                        write_private_file(&metadata_path, &metadata, overwrite).await?;
+                    }
+
+                    Ok::<_, color_eyre::Report>(data_path)
+                }
+                .await;
+
+                // Fails only if the app is quitting, in which case the result is of no interest.
+                let _ = action_tx.send(Action::BroadcastMessage(ComponentMessage::ExportFinish {
+                    result: result.map_err(|error| error.to_string()),
+                }));
+            }
+            .instrument(info_span!("export record task")),
+        );
+    }
+}
+
+/// Creates the file at `path`, readable and writable only by the current user.
+/// An existing file is only truncated if `overwrite` is set, otherwise creating the file fails, even
+/// if it was created after the user was asked whether to overwrite existing files.
+async fn write_private_file(path: &Path, contents: &[u8], overwrite: bool) -> Result<()> {
+    let mut options = tokio::fs::OpenOptions::new();
+
+    if overwrite {
+        options.write(true).create(true).truncate(true);
+    } else {
+        options.write(true).create_new(true);
+    }
+
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let mut file = options
+        .open(path)
+        .await
+        .wrap_err_with(|| format!("Failed to open {}", path.display()))?;
+
+    // The mode is only applied to newly created files.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .await?;
+    }
+
+    file.write_all(contents)
+        .await
+        .wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+    file.flush().await?;
+    Ok(())
+}
+
+impl Component for PaneExport {
+    fn update(&mut self, message: ComponentMessage) -> Result<Option<Action>> {
+        match message {
+            ComponentMessage::OnButtonPress { id } if id == self.button.get_id() => {
+                self.spawn_export_task();
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::ExportFinish { result } => {
+                self.status_spinner.content = match result {
+                    Ok(_) => get_status_finished("Exported".into(), 153.29 / 360.0),
+                    Err(error) => get_status_finished(error.into(), 29.23 / 360.0),
+                };
+                Ok(Some(Action::Render))
+            }
+            // The version being exported is no longer displayed.
+            ComponentMessage::RecordOpen { .. } | ComponentMessage::Navigate(_)
+                if self.is_open() =>
+            {
+                self.close();
+                Ok(Some(Action::Render))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                self.spawn_export_task();
+                self.button.held_down = true;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Release,
+                ..
+            })
+            | Event::FocusLost => {
+                self.button.held_down = false;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                kind: KeyEventKind::Press,
+                ..
+            }) => {
+                self.close();
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            _ => Ok(HandleEventSuccess::unhandled()),
+        }
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Export")
+                .with_hint("<Enter>", "Export the version")
+                .with_hint("<Esc>", "Cancel"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn get_children(&self) -> Vec<&dyn Component> {
+        vec![
+            &self.path_field,
+            &self.metadata_radio_array,
+            &self.status_spinner,
+            &self.button,
+        ]
+    }
+
+    fn get_children_mut(&mut self) -> Vec<&mut dyn Component> {
+        vec![
+            &mut self.path_field,
+            &mut self.metadata_radio_array,
+            &mut self.status_spinner,
+            &mut self.button,
+        ]
+    }
+}
+
+impl Drawable for PaneExport {
+    type Args<'a>
+        = PaneBottomArgs
+    where
+        Self: 'a;
+
+    fn draw<'a>(
+        &self,
+        context: &mut DrawContext,
+        area: Rect,
+        extra_args: Self::Args<'a>,
+    ) -> Result<()>
+    where
+        Self: 'a,
+    {
+        let Some(version) = self.version.as_ref() else {
+            return Ok(());
+        };
+        context.record_area(self.id, area);
+        let (area_title, area_content) = MainView::pane_areas(area, extra_args.title_offset_x);
+
+        MainView::draw_pane_title(
+            context,
+            area_title,
+            format!(
+                "Export Version v{} [Enter] [Esc] to cancel",
+                version.record_version
+            ),
+            &[self],
+        );
+
+        let layout_bottom_lines = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([
+                Constraint::Length(11),
+                Constraint::Fill(1),
+                Constraint::Length(18),
+            ]);
+        let [area_path, area_metadata] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1)])
+            .areas(area_content);
+        let [area_path_label, area_path_field, area_status] = layout_bottom_lines.areas(area_path);
+        let [area_metadata_label, area_metadata_field, area_button] =
+            layout_bottom_lines.areas(area_metadata);
+
+        context
+            .frame()
+            .render_widget(Span::raw("Path"), area_path_label);
+        self.path_field.draw(context, area_path_field, ())?;
+        self.status_spinner.draw(context, area_status, ())?;
+        context
+            .frame()
+            .render_widget(Span::raw("Metadata"), area_metadata_label);
+        self.metadata_radio_array
+            .draw(context, area_metadata_field, ())?;
+        self.button.draw(context, area_button, ())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,839 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
+
+use color_eyre::eyre::{Result, eyre};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use rrr::record::{
+    HashedRecordKey, RECORD_NAME_ROOT, RecordKey, RecordName, RecordVersion, SuccessionNonce,
+};
+use rrr::utils::serde::BytesOrAscii;
+use tokio::sync::RwLock;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, debug, info_span};
+
+use crate::action::{Action, ComponentMessage, LoadedRecordVersion, Navigation, RecordOpenStage};
+use crate::color::{ColorOklch, TextColor};
+use crate::component::{
+    Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints,
+};
+use crate::config::SearchConfig;
+use crate::error;
+use crate::registry::{AnyRegistry, SearchLimit};
+use crate::tui::Event;
+
+use super::button::Button;
+use super::checkbox::Checkbox;
+use super::encoding::Encoding;
+use super::input_field::InputField;
+use super::main_view::{
+    MainState, MainView, PaneBottomArgs, get_status_finished, get_status_in_progress_with_elapsed,
+    load_record_version,
+};
+use super::open_status::{Animation, OpenStatus, SpinnerContent};
+use super::radio_array::RadioArray;
+
+#[derive(Debug)]
+pub struct PaneOpen {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    main_state: Rc<RefCell<MainState>>,
+    record_name_field: InputField,
+    encoding_radio_array: RadioArray<Encoding>,
+    /// Shows the fields of the search limits.
+    advanced_checkbox: Checkbox,
+    version_lookahead_field: InputField,
+    collision_resolution_attempts_field: InputField,
+    status_spinner: OpenStatus<'static>,
+    button: Button,
+    /// Cancels the running search, if any. The form is disabled while a search is running.
+    search_cancellation_token: Option<CancellationToken>,
+    /// When the latest search started.
+    search_instant: Instant,
+}
+
+impl PaneOpen {
+    pub fn new(
+        id: ComponentId,
+        action_tx: &UnboundedSender<Action>,
+        main_state: &Rc<RefCell<MainState>>,
+    ) -> Result<Self> {
+        let search_limits = main_state.borrow().search_limits;
+        let mut version_lookahead_field = InputField::new(ComponentId::new(), action_tx);
+        let mut collision_resolution_attempts_field =
+            InputField::new(ComponentId::new(), action_tx);
+
+        version_lookahead_field.set_content(search_limits.max_version_lookahead.to_string());
+        collision_resolution_attempts_field
+            .set_content(search_limits.max_collision_resolution_attempts.to_string());
+
+        Ok(Self {
+            id,
+            action_tx: action_tx.clone(),
+            main_state: main_state.clone(),
+            record_name_field: InputField::new(ComponentId::new(), action_tx),
+            encoding_radio_array: RadioArray::new(
+                ComponentId::new(),
+                action_tx,
+                vec![Encoding::Utf8, Encoding::Hex],
+                &Encoding::Utf8,
+                Direction::Horizontal,
+            ),
+            advanced_checkbox: Checkbox::new(
+                ComponentId::new(),
+                action_tx,
+                "Advanced".into(),
+                false,
+            ),
+            version_lookahead_field,
+            collision_resolution_attempts_field,
+            status_spinner: OpenStatus::new(
+                ComponentId::new(),
+                action_tx,
+                SpinnerContent::default(),
+            ),
+            button: Button::new(ComponentId::new(), action_tx, "Search".into())
+                .with_form_confirmation(true)
+                .with_text_color_unfocused(TextColor::default().bg(ColorOklch::new(0.2, 0.0, 0.0)))
+                .with_text_color_focused(TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)))
+                .with_text_color_pressed(TextColor::default().bg(ColorOklch::new(0.3, 0.0, 0.0))),
+            search_cancellation_token: None,
+            search_instant: Instant::now(),
+        })
+    }
+
+    fn is_searching(&self) -> bool {
+        self.search_cancellation_token.is_some()
+    }
+
+    fn set_form_enabled(&mut self, enabled: bool) {
+        self.record_name_field.set_enabled(enabled);
+        self.encoding_radio_array.set_enabled(enabled);
+        self.advanced_checkbox.set_enabled(enabled);
+        self.version_lookahead_field.set_enabled(enabled);
+        self.collision_resolution_attempts_field
+            .set_enabled(enabled);
+        self.button.set_enabled(enabled);
+    }
+
+    /// The number of rows the pane occupies, including its title.
+    pub(super) fn get_height(&self) -> u16 {
+        if self.advanced_checkbox.checked { 4 } else { 3 }
+    }
+
+    /// Parses the search limits from the advanced section of the form.
+    fn get_search_limits(&self) -> Result<SearchConfig> {
+        let parse_count = |field: &InputField, name: &str| -> Result<u64> {
+            field
+                .get_content()
+                .trim()
+                .parse()
+                .map_err(|_| eyre!("The {name} must be a whole number"))
+        };
+        let search_limits = SearchConfig {
+            max_version_lookahead: parse_count(&self.version_lookahead_field, "lookahead")?,
+            max_collision_resolution_attempts: parse_count(
+                &self.collision_resolution_attempts_field,
+                "number of attempts",
+            )?,
+        };
+
+        if search_limits.max_collision_resolution_attempts == 0 {
+            return Err(eyre!("At least one attempt is required"));
+        }
+
+        Ok(search_limits)
+    }
+
+    /// Starts a new request to open a record, superseding the previous one, and disables the
+    /// form until the search finishes. Returns the generation of the request and the token
+    /// cancelling it.
+    fn begin_search(&mut self) -> (u64, CancellationToken) {
+        let (generation, cancellation_token) = self.main_state.borrow_mut().begin_open_request();
+
+        self.search_cancellation_token = Some(cancellation_token.clone());
+        self.search_instant = Instant::now();
+        self.set_form_enabled(false);
+        self.show_status_searching("Searching");
+
+        (generation, cancellation_token)
+    }
+
+    fn end_search(&mut self) {
+        self.search_cancellation_token = None;
+        self.set_form_enabled(true);
+    }
+
+    /// Cancels the running search, returning `false` if there is none.
+    fn cancel_search(&mut self) -> bool {
+        let Some(cancellation_token) = self.search_cancellation_token.take() else {
+            return false;
+        };
+
+        cancellation_token.cancel();
+        self.set_form_enabled(true);
+        self.show_status_finished("Cancelled".into(), 67.76 / 360.0);
+        true
+    }
+
+    fn get_record_name(&self) -> Result<RecordName> {
+        let bytes = self
+            .encoding_radio_array
+            .get_checked()
+            .decode(self.record_name_field.get_content())?;
+        Ok(BytesOrAscii(bytes))
+    }
+
+    /// A short label describing the length of the record name being entered.
+    fn get_record_name_length_span(&self) -> Span<'static> {
+        match self.get_record_name() {
+            Ok(record_name) => Span::styled(
+                format!("{} B", record_name.0.len()),
+                TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0)),
+            ),
+            Err(_) => Span::styled(
+                "invalid",
+                TextColor::default().fg(ColorOklch::new(0.6, 0.15, 29.23 / 360.0)),
+            ),
+        }
+    }
+
+    fn spawn_open_record_task(&mut self) {
+        let parent = self
+            .main_state
+            .borrow()
+            .opened_record()
+            .map(|opened_record| opened_record.hashed_record_key.clone());
+
+        // The limits are kept for opening records through navigation, too.
+        match self.get_search_limits() {
+            Ok(search_limits) => self.main_state.borrow_mut().search_limits = search_limits,
+            Err(error) => {
+                self.show_status_finished(error.to_string().into(), 29.23 / 360.0);
+                return;
+            }
+        }
+
+        match self.get_record_name() {
+            Ok(record_name) => self.spawn_open_record_task_with_record_name(record_name, parent),
+            Err(error) => self.show_status_finished(error.to_string().into(), 29.23 / 360.0),
+        }
+    }
+
+    /// Displays a final status message, fading from the accent color of `hue` to gray.
+    fn show_status_finished(&mut self, text: Cow<'static, str>, hue: f32) {
+        self.status_spinner.content = get_status_finished(text, hue);
+    }
+
+    /// Displays the stage of the running search, along with the time elapsed since it started.
+    fn show_status_searching(&mut self, stage: impl Display) {
+        self.status_spinner.content = get_status_in_progress_with_elapsed(
+            format!(" {stage}…").into(),
+            Some(self.search_instant),
+        );
+    }
+
+    /// Searches for the record named `record_name` among the successors of `parent`, or among
+    /// the top-level records if `parent` is `None`.
+    pub(super) fn spawn_open_record_task_with_record_name(
+        &mut self,
+        record_name: RecordName,
+        parent: Option<HashedRecordKey>,
+    ) {
+        // The main state is being cloned just because `MainState::get_succession_nonce`
+        // is an async function that needs to be awaited from within an async block.
+        // If this function ever becomes async, it should be moved up out of the async task.
+        let main_state_clone = self.main_state.borrow().clone();
+        let action_tx = self.action_tx.clone();
+        let (generation, cancellation_token) = self.begin_search();
+
+        tokio::spawn(
+            async move {
+                let error_tx = action_tx.clone();
+                let search = error::report_open_request(&error_tx, generation, async move || {
+                    let registry = &*main_state_clone.registry;
+
+                    if parent.as_ref().is_some_and(|parent| {
+                        main_state_clone
+                            .get_cached_succession_nonce(parent)
+                            .is_none()
+                    }) {
+                        Self::report_progress(
+                            &action_tx,
+                            generation,
+                            RecordOpenStage::DerivingNonce,
+                        )?;
+                    }
+
+                    let current_succession_nonce = main_state_clone
+                        .get_succession_nonce(&action_tx, parent.as_ref())
+                        .await?;
+                    // The key owns its copy of the nonce, which is dropped right after hashing.
+                    let record_key = RecordKey {
+                        predecessor_nonce: SuccessionNonce::clone(&current_succession_nonce),
+                        record_name: record_name.clone(),
+                    };
+
+                    Self::report_progress(&action_tx, generation, RecordOpenStage::HashingKey)?;
+
+                    let hashed_record_key = record_key
+                        .hash(&registry.read().await.config().hash)
+                        .await?;
+
+                    Self::open_record(
+                        &action_tx,
+                        registry,
+                        main_state_clone.search_limits,
+                        generation,
+                        hashed_record_key,
+                        record_name,
+                        parent,
+                    )
+                    .await
+                });
+
+                tokio::select! {
+                    () = cancellation_token.cancelled() => debug!("Search cancelled."),
+                    () = search => {}
+                }
+            }
+            .instrument(info_span!("open record task")),
+        );
+    }
+
+    /// Opens a record that has already been found before, without hashing its key again.
+    fn spawn_reopen_record_task(
+        &mut self,
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<HashedRecordKey>,
+    ) {
+        let registry = self.main_state.borrow().registry.clone();
+        let search_limits = self.main_state.borrow().search_limits;
+        let action_tx = self.action_tx.clone();
+        let (generation, cancellation_token) = self.begin_search();
+
+        tokio::spawn(
+            async move {
+                let error_tx = action_tx.clone();
+                let search = error::report_open_request(&error_tx, generation, async move || {
+                    Self::open_record(
+                        &action_tx,
+                        &registry,
+                        search_limits,
+                        generation,
+                        hashed_record_key,
+                        record_name,
+                        parent,
+                    )
+                    .await
+                });
+
+                tokio::select! {
+                    () = cancellation_token.cancelled() => debug!("Search cancelled."),
+                    () = search => {}
+                }
+            }
+            .instrument(info_span!("reopen record task")),
+        );
+    }
+
+    fn report_progress(
+        action_tx: &UnboundedSender<Action>,
+        generation: u64,
+        stage: RecordOpenStage,
+    ) -> Result<()> {
+        action_tx.send(Action::BroadcastMessage(
+            ComponentMessage::RecordOpenProgress { generation, stage },
+        ))?;
+
+        Ok(())
+    }
+
+    /// Loads the versions of the record and broadcasts the result of the request identified by
+    /// `generation`.
+    pub(super) async fn open_record(
+        action_tx: &UnboundedSender<Action>,
+        registry: &RwLock<AnyRegistry>,
+        search_limits: SearchConfig,
+        generation: u64,
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<HashedRecordKey>,
+    ) -> Result<()> {
+        let registry = registry.read().await;
+        let (versions, latest_version, search_limit) = Self::load_record_versions(
+            action_tx,
+            generation,
+            &hashed_record_key,
+            &registry,
+            search_limits,
+        )
+        .await?;
+        // Derived right away, as the sub-records of an opened record are likely to be searched.
+        let succession_nonce = if latest_version.is_some() {
+            Self::report_progress(action_tx, generation, RecordOpenStage::DerivingNonce)?;
+
+            let succession_nonce = hashed_record_key
+                .derive_succession_nonce(&registry.config().kdf)
+                .await?;
+
+            Some(Arc::new(succession_nonce))
+        } else {
+            None
+        };
+
+        drop(registry);
+        debug!(?versions, ?latest_version, "Sending read result.");
+
+        action_tx.send(Action::BroadcastMessage(ComponentMessage::RecordOpen {
+            generation,
+            hashed_record_key,
+            record_name,
+            parent,
+            versions,
+            latest_version,
+            succession_nonce,
+            search_limit,
+        }))?;
+
+        Ok(())
+    }
+
+    /// Lists the available versions of the record in ascending order and loads the latest one,
+    /// reporting the progress of the request identified by `generation`.
+    /// Older versions are only loaded once they are selected in the versions pane.
+    /// Also returns the search limit that ended the listing short of an existing version, if any.
+    async fn load_record_versions(
+        action_tx: &UnboundedSender<Action>,
+        generation: u64,
+        hashed_record_key: &HashedRecordKey,
+        registry: &AnyRegistry,
+        search_limits: SearchConfig,
+    ) -> Result<(
+        Vec<RecordVersion>,
+        Option<LoadedRecordVersion>,
+        Option<SearchLimit>,
+    )> {
+        Self::report_progress(
+            action_tx,
+            generation,
+            RecordOpenStage::ListingVersions {
+                attempts: search_limits.max_collision_resolution_attempts,
+            },
+        )?;
+
+        let versions = registry
+            .list_record_versions(
+                hashed_record_key,
+                search_limits.max_version_lookahead,
+                search_limits.max_collision_resolution_attempts,
+            )
+            .await?;
+
+        Self::report_progress(action_tx, generation, RecordOpenStage::ProbingSearchLimits)?;
+
+        let search_limit = registry
+            .probe_search_limits(
+                hashed_record_key,
+                &versions,
+                search_limits.max_version_lookahead,
+                search_limits.max_collision_resolution_attempts,
+            )
+            .await?;
+        let Some(latest_record_version) = versions.last().copied() else {
+            return Ok((versions, None, search_limit));
+        };
+
+        Self::report_progress(
+            action_tx,
+            generation,
+            RecordOpenStage::LoadingVersion {
+                version: versions.len(),
+                versions: versions.len(),
+            },
+        )?;
+
+        let latest_version = load_record_version(
+            registry,
+            hashed_record_key,
+            latest_record_version,
+            search_limits,
+        )
+        .await?;
+
+        Ok((versions, Some(latest_version), search_limit))
+    }
+}
+
+impl Component for PaneOpen {
+    fn update(&mut self, message: ComponentMessage) -> Result<Option<Action>> {
+        // The search was superseded by another request to open a record, such as after saving.
+        if self
+            .search_cancellation_token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            self.end_search();
+            self.status_spinner.content = SpinnerContent::default();
+        }
+
+        match message {
+            ComponentMessage::OnButtonPress { id } if id == self.button.get_id() => {
+                self.spawn_open_record_task();
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::RecordOpen {
+                generation,
+                latest_version,
+                search_limit,
+                ..
+            } => {
+                let main_state = self.main_state.borrow();
+
+                if !main_state.is_latest_open_request(generation) {
+                    return Ok(None);
+                }
+
+                let search_limits = main_state.search_limits;
+
+                drop(main_state);
+                self.end_search();
+
+                let found = if latest_version.is_some() {
+                    self.record_name_field.reset_content();
+                    "Found"
+                } else {
+                    "Not found"
+                };
+
+                match search_limit {
+                    Some(SearchLimit::VersionLookahead(record_version)) => self
+                        .show_status_finished(
+                            format!(
+                                "{found}, but v{record_version} exists past the lookahead of {}",
+                                search_limits.max_version_lookahead
+                            )
+                            .into(),
+                            67.76 / 360.0,
+                        ),
+                    Some(SearchLimit::CollisionResolutionAttempts(record_version)) => self
+                        .show_status_finished(
+                            format!(
+                                "{found}, but v{record_version} needs more than {} attempts",
+                                search_limits.max_collision_resolution_attempts
+                            )
+                            .into(),
+                            67.76 / 360.0,
+                        ),
+                    None if latest_version.is_some() => {
+                        self.show_status_finished("Record found".into(), 153.29 / 360.0);
+                    }
+                    None => self.show_status_finished(
+                        format!(
+                            "Record not found (lookahead {}, {} attempts)",
+                            search_limits.max_version_lookahead,
+                            search_limits.max_collision_resolution_attempts
+                        )
+                        .into(),
+                        67.76 / 360.0,
+                    ),
+                }
+
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::RecordOpenProgress { generation, stage }
+                if self.is_searching()
+                    && self.main_state.borrow().is_latest_open_request(generation) =>
+            {
+                self.show_status_searching(stage);
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::RecordReopen {
+                hashed_record_key,
+                record_name,
+                parent,
+            } => {
+                self.spawn_reopen_record_task(hashed_record_key, record_name, parent);
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::Navigate(Navigation::Home) => {
+                self.spawn_open_record_task_with_record_name(RECORD_NAME_ROOT, None);
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::ShowError {
+                error,
+                open_generation,
+            } => {
+                if let Some(open_generation) = open_generation {
+                    // Errors of superseded requests are stale.
+                    if !self
+                        .main_state
+                        .borrow()
+                        .is_latest_open_request(open_generation)
+                    {
+                        return Ok(None);
+                    }
+
+                    self.end_search();
+                }
+
+                // Errors of other tasks are shown without ending the running search.
+                self.show_status_finished(error.into(), 29.23 / 360.0);
+                Ok(Some(Action::Render))
+            }
+            // The status of a running search takes precedence.
+            ComponentMessage::ClipboardCopy { clear_instant } if !self.is_searching() => {
+                self.status_spinner.content = match clear_instant {
+                    Some(clear_instant) => SpinnerContent::default()
+                        .with_text("Copied,".into())
+                        .with_animation(Some(Animation::Countdown {
+                            instant_start: Instant::now(),
+                            instant_end: clear_instant,
+                            color_start: TextColor::default().fg(ColorOklch::new(
+                                0.79,
+                                0.1603,
+                                67.76 / 360.0,
+                            )),
+                            color_end: TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0)),
+                        })),
+                    None => get_status_finished("Copied".into(), 153.29 / 360.0),
+                };
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::ClipboardClear if !self.is_searching() => {
+                // Terminals may ignore the request to clear the clipboard.
+                self.show_status_finished("Cleared (best effort)".into(), 153.29 / 360.0);
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::RecordSave {
+                result: Ok(record_version),
+            } => {
+                self.show_status_finished(
+                    format!("Saved v{record_version}").into(),
+                    153.29 / 360.0,
+                );
+                Ok(Some(Action::Render))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                // Only a single search may run at a time.
+                if !self.is_searching() {
+                    self.spawn_open_record_task();
+                    self.button.held_down = true;
+                }
+
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Release,
+                ..
+            })
+            | Event::FocusLost => {
+                self.button.held_down = false;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                kind: KeyEventKind::Press,
+                ..
+            }) if self.cancel_search() => {
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            _ => Ok(HandleEventSuccess::unhandled()),
+        }
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(if self.is_searching() {
+            KeyHints::new("Open Sub-Record").with_hint("<Esc>", "Cancel the search")
+        } else {
+            KeyHints::new("Open Sub-Record").with_hint("<Enter>", "Search for the record")
+        })
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn get_children(&self) -> Vec<&dyn Component> {
+        let mut children: Vec<&dyn Component> = vec![
+            &self.record_name_field,
+            &self.encoding_radio_array,
+            &self.advanced_checkbox,
+        ];
+
+        if self.advanced_checkbox.checked {
+            children.extend([
+                &self.version_lookahead_field as &dyn Component,
+                &self.collision_resolution_attempts_field,
+            ]);
+        }
+
+        children.extend([&self.status_spinner as &dyn Component, &self.button]);
+        children
+    }
+
+    fn get_children_mut(&mut self) -> Vec<&mut dyn Component> {
+        let advanced = self.advanced_checkbox.checked;
+        let mut children: Vec<&mut dyn Component> = vec![
+            &mut self.record_name_field,
+            &mut self.encoding_radio_array,
+            &mut self.advanced_checkbox,
+        ];
+
+        if advanced {
+            children.extend([
+                &mut self.version_lookahead_field as &mut dyn Component,
+                &mut self.collision_resolution_attempts_field,
+            ]);
+        }
+
+        children.extend([
+            &mut self.status_spinner as &mut dyn Component,
+            &mut self.button,
+        ]);
+        children
+    }
+}
+
+impl Drawable for PaneOpen {
+    type Args<'a>
+        = PaneBottomArgs
+    where
+        Self: 'a;
+
+    fn draw<'a>(
+        &self,
+        context: &mut DrawContext,
+        area: Rect,
+        extra_args: Self::Args<'a>,
+    ) -> Result<()>
+    where
+        Self: 'a,
+    {
+        context.record_area(self.id, area);
+        let (area_title, area_content) = MainView::pane_areas(area, extra_args.title_offset_x);
+
+        MainView::draw_pane_title(context, area_title, "Open Sub-Record [Enter]", &[self]);
+
+        let layout_bottom_lines = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([
+                Constraint::Length(11),
+                Constraint::Fill(1),
+                Constraint::Length(24),
+            ]);
+        let [area_record_name, area_encoding, area_limits] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(area_content);
+        let [area_record_name_label, area_record_name_field, area_status] =
+            layout_bottom_lines.areas(area_record_name);
+        let [area_encoding_label, area_encoding_field, area_button] =
+            layout_bottom_lines.areas(area_encoding);
+        let [area_encoding_field, area_advanced] = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(self.advanced_checkbox.size().width),
+            ])
+            .areas(area_encoding_field);
+
+        let record_name_length_span = self.get_record_name_length_span();
+        let [area_record_name_field, area_record_name_length] = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(record_name_length_span.width() as u16),
+            ])
+            .areas(area_record_name_field);
+
+        context
+            .frame()
+            .render_widget(Span::raw("Record Name"), area_record_name_label);
+        self.record_name_field
+            .draw(context, area_record_name_field, ())?;
+        context
+            .frame()
+            .render_widget(record_name_length_span, area_record_name_length);
+        self.status_spinner.draw(context, area_status, ())?;
+        context
+            .frame()
+            .render_widget(Span::raw("Encoding"), area_encoding_label);
+        self.encoding_radio_array
+            .draw(context, area_encoding_field, ())?;
+        self.advanced_checkbox.draw(context, area_advanced, ())?;
+        self.button.draw(context, area_button, ())?;
+
+        if self.advanced_checkbox.checked {
+            let [area_limits_label, area_limits_fields, _] = layout_bottom_lines.areas(area_limits);
+            let lookahead_label = Span::raw("Lookahead");
+            let attempts_label = Span::raw("Collision Attempts");
+            let [
+                area_lookahead_label,
+                area_lookahead_field,
+                area_attempts_label,
+                area_attempts_field,
+            ] = Layout::default()
+                .direction(Direction::Horizontal)
+                .spacing(1)
+                .constraints([
+                    Constraint::Length(lookahead_label.width() as u16),
+                    Constraint::Fill(1),
+                    Constraint::Length(attempts_label.width() as u16),
+                    Constraint::Fill(1),
+                ])
+                .areas(area_limits_fields);
+
+            context
+                .frame()
+                .render_widget(Span::raw("Limits"), area_limits_label);
+            context
+                .frame()
+                .render_widget(lookahead_label, area_lookahead_label);
+            self.version_lookahead_field
+                .draw(context, area_lookahead_field, ())?;
+            context
+                .frame()
+                .render_widget(attempts_label, area_attempts_label);
+            self.collision_resolution_attempts_field
+                .draw(context, area_attempts_field, ())?;
+
+            if self.is_searching() {
+                context.dim(area_limits);
+            }
+        }
+
+        if self.is_searching() {
+            context.dim(area_record_name_label);
+            context.dim(area_record_name_length);
+            context.dim(area_encoding_label);
+        }
+
+        Ok(())
+    }
+}
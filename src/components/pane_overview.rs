@@ -0,0 +1,80 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use color_eyre::eyre::Result;
+use ratatui::prelude::*;
+
+use crate::color::{ColorOklch, TextColor};
+use crate::component::{Component, ComponentId, DrawContext, Drawable};
+
+use super::main_view::{MainState, MainView};
+use super::shard_map::ShardMap;
+
+/// Shows how many shards of the registry were visited so far, and where they are.
+#[derive(Debug)]
+pub struct PaneOverview {
+    id: ComponentId,
+    main_state: Rc<RefCell<MainState>>,
+}
+
+impl PaneOverview {
+    pub fn new(id: ComponentId, main_state: &Rc<RefCell<MainState>>) -> Self {
+        Self {
+            id,
+            main_state: main_state.clone(),
+        }
+    }
+}
+
+impl Component for PaneOverview {
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+}
+
+impl Drawable for PaneOverview {
+    type Args<'a>
+        = ()
+    where
+        Self: 'a;
+
+    fn draw<'a>(&self, context: &mut DrawContext, area: Rect, (): Self::Args<'a>) -> Result<()>
+    where
+        Self: 'a,
+    {
+        context.record_area(self.id, area);
+        let (area_title, area_content) = MainView::pane_areas(area, 0);
+        let [area_stats, area_map] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Fill(1)])
+            .areas(area_content);
+        let state = self.main_state.borrow();
+        let shards = state.shards.lock().unwrap();
+        let label_color = TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0));
+        let stats = Text::from(vec![
+            Line::from_iter([
+                Span::styled("Available Shards: ", &label_color),
+                Span::raw(shards.available.len().to_string()),
+            ]),
+            Line::from_iter([
+                Span::styled("Visited Shards: ", &label_color),
+                Span::raw(format!(
+                    "{} ({:.0}%)",
+                    shards.visited.len(),
+                    100.0 * shards.visited_ratio()
+                )),
+            ]),
+        ]);
+
+        MainView::draw_pane_title(context, area_title, "[O]verview", &[self]);
+        context.frame().render_widget(stats, area_stats);
+        context
+            .frame()
+            .render_widget(ShardMap { shards: &shards }, area_map);
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use color_eyre::eyre::{Result, eyre};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::{Action, ComponentMessage};
+use crate::color::{ColorOklch, TextColor};
+use crate::component::{
+    Component, ComponentId, DefaultDrawable, DrawContext, Drawable, HandleEventSuccess, KeyHints,
+};
+use crate::config::{Config, SavedConfig};
+use crate::tui::Event;
+
+use super::button::Button;
+use super::checkbox::Checkbox;
+use super::content_view_mode::ContentViewMode;
+use super::input_field::InputField;
+use super::main_view::{MainView, get_status_finished};
+use super::open_status::{OpenStatus, SpinnerContent};
+use super::radio_array::RadioArray;
+
+/// A form for editing the configuration, replacing all panes while open.
+/// Saved values are applied right away where possible, and written to the user configuration file.
+#[derive(Debug)]
+pub struct PaneSettings {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    /// The configuration being edited, set while the form is open.
+    config: Option<Arc<Config>>,
+    tick_rate_field: InputField,
+    frame_rate_field: InputField,
+    clipboard_clear_timeout_field: InputField,
+    max_width_field: InputField,
+    max_height_field: InputField,
+    content_view_mode_radio_array: RadioArray<ContentViewMode>,
+    reveal_breadcrumb_checkbox: Checkbox,
+    mouse_checkbox: Checkbox,
+    status_spinner: OpenStatus<'static>,
+    button: Button,
+}
+
+impl PaneSettings {
+    pub fn new(id: ComponentId, action_tx: &UnboundedSender<Action>) -> Self {
+        Self {
+            id,
+            action_tx: action_tx.clone(),
+            config: None,
+            tick_rate_field: InputField::new(ComponentId::new(), action_tx),
+            frame_rate_field: InputField::new(ComponentId::new(), action_tx),
+            clipboard_clear_timeout_field: InputField::new(ComponentId::new(), action_tx),
+            max_width_field: InputField::new(ComponentId::new(), action_tx),
+            max_height_field: InputField::new(ComponentId::new(), action_tx),
+            content_view_mode_radio_array: RadioArray::new(
+                ComponentId::new(),
+                action_tx,
+                vec![
+                    ContentViewMode::Auto,
+                    ContentViewMode::Text,
+                    ContentViewMode::Hex,
+                    ContentViewMode::Cbor,
+                ],
+                &ContentViewMode::Auto,
+                Direction::Horizontal,
+            ),
+            reveal_breadcrumb_checkbox: Checkbox::new(
+                ComponentId::new(),
+                action_tx,
+                "Reveal the record names initially".into(),
+                false,
+            ),
+            mouse_checkbox: Checkbox::new(
+                ComponentId::new(),
+                action_tx,
+                "Enable mouse input, disabling text selection in the terminal".into(),
+                false,
+            ),
+            status_spinner: OpenStatus::new(
+                ComponentId::new(),
+                action_tx,
+                SpinnerContent::default(),
+            ),
+            button: Button::new(ComponentId::new(), action_tx, "Save".into())
+                .with_form_confirmation(true)
+                .with_text_color_unfocused(TextColor::default().bg(ColorOklch::new(0.2, 0.0, 0.0)))
+                .with_text_color_focused(TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)))
+                .with_text_color_pressed(TextColor::default().bg(ColorOklch::new(0.3, 0.0, 0.0))),
+        }
+    }
+
+    pub(super) fn is_open(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// Opens the form with the values of `config`, returning the action focusing its first field.
+    pub(super) fn open(&mut self, config: &Arc<Config>) -> Action {
+        let format_optional = |value: Option<u16>| value.map(|value| value.to_string());
+
+        self.tick_rate_field
+            .set_content(config.tick_rate.to_string());
+        self.frame_rate_field
+            .set_content(config.frame_rate.to_string());
+        self.clipboard_clear_timeout_field
+            .set_content(config.ui.clipboard_clear_timeout.to_string());
+        self.max_width_field
+            .set_content(format_optional(config.ui.max_width).unwrap_or_default());
+        self.max_height_field
+            .set_content(format_optional(config.ui.max_height).unwrap_or_default());
+        self.content_view_mode_radio_array
+            .set_checked(&config.ui.content_view_mode);
+        self.reveal_breadcrumb_checkbox.checked = config.ui.reveal_breadcrumb;
+        self.mouse_checkbox.checked = config.ui.mouse;
+        self.status_spinner.content = SpinnerContent::default();
+        self.config = Some(config.clone());
+
+        Action::FocusComponent(self.tick_rate_field.get_id())
+    }
+
+    fn close(&mut self) {
+        self.config = None;
+        self.button.held_down = false;
+    }
+
+    /// Builds the configuration from the form, keeping the values of `config` that are not
+    /// editable in it.
+    fn get_config(&self, config: &Config) -> Result<Config> {
+        fn parse_number<T: FromStr>(field: &InputField, name: &str) -> Result<T> {
+            field
+                .get_content()
+                .trim()
+                .parse()
+                .map_err(|_| eyre!("The {name} must be a number"))
+        }
+
+        fn parse_optional_number<T: FromStr>(field: &InputField, name: &str) -> Result<Option<T>> {
+            if field.get_content().trim().is_empty() {
+                Ok(None)
+            } else {
+                parse_number(field, name).map(Some)
+            }
+        }
+
+        let mut config = config.clone();
+
+        config.tick_rate = parse_number(&self.tick_rate_field, "tick rate")?;
+        config.frame_rate = parse_number(&self.frame_rate_field, "frame rate")?;
+        config.ui.clipboard_clear_timeout =
+            parse_number(&self.clipboard_clear_timeout_field, "clipboard timeout")?;
+        config.ui.max_width = parse_optional_number(&self.max_width_field, "maximum width")?;
+        config.ui.max_height = parse_optional_number(&self.max_height_field, "maximum height")?;
+        config.ui.content_view_mode = *self.content_view_mode_radio_array.get_checked();
+        config.ui.reveal_breadcrumb = self.reveal_breadcrumb_checkbox.checked;
+        config.ui.mouse = self.mouse_checkbox.checked;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Validates and saves the configuration, applying it on success.
+    fn save(&mut self) -> Result<()> {
+        let Some(config) = self.config.as_ref() else {
+            return Ok(());
+        };
+        let result = self.get_config(config).and_then(|edited| {
+            let saved = config.save(&edited)?;
+            Ok((edited, saved))
+        });
+
+        self.status_spinner.content = match result {
+            Ok((config, saved)) => {
+                let config = Arc::new(config);
+
+                self.action_tx.send(Action::ApplyConfig(config.clone()))?;
+                self.config = Some(config);
+
+                match saved {
+                    None => get_status_finished("No changes to save".into(), 153.29 / 360.0),
+                    Some(SavedConfig {
+                        path,
+                        overridden_keys,
+                    }) if overridden_keys.is_empty() => get_status_finished(
+                        format!("Saved to {}", path.display()).into(),
+                        153.29 / 360.0,
+                    ),
+                    Some(SavedConfig {
+                        path,
+                        overridden_keys,
+                    }) => get_status_finished(
+                        format!(
+                            "Saved to {}, but overridden by other sources: {}",
+                            path.display(),
+                            overridden_keys.join(", ")
+                        )
+                        .into(),
+                        67.76 / 360.0,
+                    ),
+                }
+            }
+            Err(error) => get_status_finished(format!("{error:#}").into(), 29.23 / 360.0),
+        };
+
+        Ok(())
+    }
+}
+
+impl Component for PaneSettings {
+    fn update(&mut self, message: ComponentMessage) -> Result<Option<Action>> {
+        match message {
+            ComponentMessage::OnButtonPress { id } if id == self.button.get_id() => {
+                self.save()?;
+                Ok(Some(Action::Render))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                self.save()?;
+                self.button.held_down = true;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Release,
+                ..
+            })
+            | Event::FocusLost => {
+                self.button.held_down = false;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                kind: KeyEventKind::Press,
+                ..
+            }) => {
+                self.close();
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            // The settings are modal, the mnemonics of the hidden panes must not fire.
+            Event::Key(_) => Ok(HandleEventSuccess::handled()),
+            _ => Ok(HandleEventSuccess::unhandled()),
+        }
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Settings")
+                .with_hint("<Enter>", "Save and apply the settings")
+                .with_hint("<Esc>", "Close"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn get_children(&self) -> Vec<&dyn Component> {
+        vec![
+            &self.tick_rate_field,
+            &self.frame_rate_field,
+            &self.clipboard_clear_timeout_field,
+            &self.max_width_field,
+            &self.max_height_field,
+            &self.content_view_mode_radio_array,
+            &self.reveal_breadcrumb_checkbox,
+            &self.mouse_checkbox,
+            &self.status_spinner,
+            &self.button,
+        ]
+    }
+
+    fn get_children_mut(&mut self) -> Vec<&mut dyn Component> {
+        vec![
+            &mut self.tick_rate_field,
+            &mut self.frame_rate_field,
+            &mut self.clipboard_clear_timeout_field,
+            &mut self.max_width_field,
+            &mut self.max_height_field,
+            &mut self.content_view_mode_radio_array,
+            &mut self.reveal_breadcrumb_checkbox,
+            &mut self.mouse_checkbox,
+            &mut self.status_spinner,
+            &mut self.button,
+        ]
+    }
+}
+
+impl Drawable for PaneSettings {
+    type Args<'a>
+        = ()
+    where
+        Self: 'a;
+
+    fn draw<'a>(&self, context: &mut DrawContext, area: Rect, (): Self::Args<'a>) -> Result<()>
+    where
+        Self: 'a,
+    {
+        if !self.is_open() {
+            return Ok(());
+        }
+
+        context.record_area(self.id, area);
+        let (area_title, area_content) = MainView::pane_areas(area, 0);
+
+        MainView::draw_pane_title(
+            context,
+            area_title,
+            "Settings [Enter] to save [Esc] to close",
+            &[self],
+        );
+
+        let layout_lines = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([Constraint::Length(24), Constraint::Fill(1)]);
+        let rows: [(&str, &dyn DefaultDrawable); 8] = [
+            ("Tick Rate (per second)", &self.tick_rate_field),
+            ("Frame Rate (per second)", &self.frame_rate_field),
+            ("Clipboard Timeout (s)", &self.clipboard_clear_timeout_field),
+            ("Max Width", &self.max_width_field),
+            ("Max Height", &self.max_height_field),
+            ("Content Format", &self.content_view_mode_radio_array),
+            ("Breadcrumb", &self.reveal_breadcrumb_checkbox),
+            ("Mouse", &self.mouse_checkbox),
+        ];
+        let [area_rows, _, area_footer] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(rows.len() as u16),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(area_content);
+        let areas_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(rows.iter().map(|_| Constraint::Length(1)))
+            .split(area_rows);
+        let [area_status, area_button] = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([Constraint::Fill(1), Constraint::Length(18)])
+            .areas(area_footer);
+
+        for ((label, field), area_row) in rows.into_iter().zip(areas_rows.iter()) {
+            let [area_label, area_field] = layout_lines.areas(*area_row);
+
+            context.frame().render_widget(Span::raw(label), area_label);
+            field.default_draw(context, area_field)?;
+        }
+
+        self.status_spinner.draw(context, area_status, ())?;
+        self.button.draw(context, area_button, ())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use color_eyre::eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{Instrument, info_span};
+
+use crate::action::{Action, ComponentMessage};
+use crate::color::{ColorOklch, TextColor};
+use crate::component::{
+    Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints,
+};
+use crate::error;
+use crate::tui::Event;
+
+use super::main_view::{
+    ListedRecordVersion, MainState, MainView, OpenedRecord, VersionLoad, load_record_version,
+};
+
+/// Lists the versions of the opened record, and allows choosing the displayed one.
+#[derive(Debug)]
+pub struct PaneVersions {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    main_state: Rc<RefCell<MainState>>,
+    /// The index of the selected version, if it differs from the displayed one.
+    selected_index: Option<usize>,
+}
+
+impl PaneVersions {
+    pub fn new(
+        id: ComponentId,
+        action_tx: &UnboundedSender<Action>,
+        main_state: &Rc<RefCell<MainState>>,
+    ) -> Self {
+        Self {
+            id,
+            action_tx: action_tx.clone(),
+            main_state: main_state.clone(),
+            selected_index: None,
+        }
+    }
+
+    /// Selects the version at `index` of the opened record, loading it in the background if it
+    /// was not requested yet.
+    fn select(&mut self, main_state: &mut MainState, index: usize) {
+        self.selected_index = Some(index);
+
+        let registry = main_state.registry.clone();
+        let search_limits = main_state.search_limits;
+        let Some(opened_record) = main_state.opened_record_mut() else {
+            return;
+        };
+        let listed_version = &mut opened_record.versions[index];
+
+        if !matches!(listed_version.load, VersionLoad::Pending) {
+            return;
+        }
+
+        listed_version.load = VersionLoad::Loading;
+
+        let hashed_record_key = opened_record.hashed_record_key.clone();
+        let record_version = listed_version.record_version;
+        let action_tx = self.action_tx.clone();
+
+        tokio::spawn(
+            async move {
+                let error_tx = action_tx.clone();
+
+                error::report(&error_tx, async move || {
+                    let result = load_record_version(
+                        &*registry.read().await,
+                        &hashed_record_key,
+                        record_version,
+                        search_limits,
+                    )
+                    .await;
+
+                    action_tx.send(Action::BroadcastMessage(
+                        ComponentMessage::RecordVersionLoad {
+                            hashed_record_key,
+                            record_version,
+                            result: result.map_err(|error| error.to_string()),
+                        },
+                    ))?;
+
+                    Ok(())
+                })
+                .await;
+            }
+            .instrument(info_span!("load record version task")),
+        );
+    }
+
+    fn get_selected_index(&self, opened_record: &OpenedRecord) -> usize {
+        self.selected_index
+            .filter(|selected_index| *selected_index < opened_record.versions.len())
+            .unwrap_or(opened_record.version_index)
+    }
+
+    fn get_version_line(listed_version: &ListedRecordVersion, latest: bool) -> Line<'_> {
+        let mut line = Line::from(format!("v{}", listed_version.record_version));
+
+        if latest {
+            line.push_span(Span::styled(
+                " latest",
+                TextColor::default().fg(ColorOklch::new(0.79, 0.1603, 153.29 / 360.0)),
+            ));
+        }
+
+        match &listed_version.load {
+            VersionLoad::Pending => {}
+            VersionLoad::Loading => line.push_span(Span::styled(
+                " Loading…",
+                TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0)),
+            )),
+            VersionLoad::Loaded(loaded_version) => {
+                for (key, value) in loaded_version.record.metadata.iter_with_semantic_keys() {
+                    line.push_span(Span::raw(" "));
+                    line.extend(crate::cbor::record_metadata_to_line(key, value).spans);
+                }
+            }
+            VersionLoad::Failed(error) => line.push_span(Span::styled(
+                format!(" {error}"),
+                TextColor::default().fg(ColorOklch::new(0.79, 0.1603, 29.23 / 360.0)),
+            )),
+        }
+
+        line
+    }
+}
+
+impl Component for PaneVersions {
+    fn is_focusable(&self) -> bool {
+        self.main_state.borrow().opened_record().is_some()
+    }
+
+    fn update(&mut self, message: ComponentMessage) -> Result<Option<Action>> {
+        match message {
+            ComponentMessage::RecordOpen { .. } | ComponentMessage::Navigate(_) => {
+                self.selected_index = None;
+                Ok(None)
+            }
+            ComponentMessage::RecordVersionLoad {
+                hashed_record_key,
+                record_version,
+                result,
+            } => {
+                let mut main_state = self.main_state.borrow_mut();
+                let load = match result {
+                    Ok(loaded_version) => VersionLoad::Loaded(loaded_version),
+                    Err(error) => VersionLoad::Failed(error),
+                };
+
+                // The record may have been opened again since, in which case its versions are
+                // requested anew.
+                for listed_version in main_state
+                    .history
+                    .iter_mut()
+                    .filter(|opened_record| opened_record.hashed_record_key == hashed_record_key)
+                    .flat_map(|opened_record| &mut opened_record.versions)
+                    .filter(|listed_version| {
+                        listed_version.record_version == record_version
+                            && matches!(listed_version.load, VersionLoad::Loading)
+                    })
+                {
+                    listed_version.load = load.clone();
+                }
+
+                Ok(Some(Action::Render))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        let Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event
+        else {
+            return Ok(HandleEventSuccess::unhandled());
+        };
+        let main_state = self.main_state.clone();
+        let mut main_state = main_state.borrow_mut();
+        let Some(opened_record) = main_state.opened_record_mut() else {
+            return Ok(HandleEventSuccess::unhandled());
+        };
+        let selected_index = self.get_selected_index(opened_record);
+        let last_index = opened_record.versions.len() - 1;
+        let selected_loaded = opened_record.versions[selected_index].loaded().is_some();
+        let displayed_version_change =
+            Action::BroadcastMessage(ComponentMessage::DisplayedVersionChange);
+
+        // Versions are listed from the latest to the oldest.
+        match code {
+            KeyCode::Up => self.select(&mut main_state, (selected_index + 1).min(last_index)),
+            KeyCode::Down => self.select(&mut main_state, selected_index.saturating_sub(1)),
+            // Versions can only be displayed or compared once they are loaded.
+            KeyCode::Enter | KeyCode::Char('d') if !selected_loaded => {}
+            KeyCode::Enter => {
+                opened_record.version_index = selected_index;
+                opened_record.diff_version_index = None;
+                self.selected_index = None;
+                return Ok(HandleEventSuccess::handled().with_action(displayed_version_change));
+            }
+            KeyCode::Char('d') => {
+                // Compare the selected version against the displayed one.
+                opened_record.diff_version_index = (opened_record.diff_version_index
+                    != Some(selected_index)
+                    && selected_index != opened_record.version_index)
+                    .then_some(selected_index);
+                return Ok(HandleEventSuccess::handled().with_action(displayed_version_change));
+            }
+            KeyCode::Esc if opened_record.diff_version_index.is_some() => {
+                opened_record.diff_version_index = None;
+                return Ok(HandleEventSuccess::handled().with_action(displayed_version_change));
+            }
+            _ => return Ok(HandleEventSuccess::unhandled()),
+        }
+
+        Ok(HandleEventSuccess::handled().with_action(Action::Render))
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Versions")
+                .with_hint("<Up> <Down>", "Select an older or newer version")
+                .with_hint("<Enter>", "Display the selected version")
+                .with_hint(
+                    "<d>",
+                    "Compare the selected version against the displayed one",
+                )
+                .with_hint("<Esc>", "Close the comparison"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+}
+
+impl Drawable for PaneVersions {
+    type Args<'a>
+        = ()
+    where
+        Self: 'a;
+
+    fn draw<'a>(&self, context: &mut DrawContext, area: Rect, (): Self::Args<'a>) -> Result<()>
+    where
+        Self: 'a,
+    {
+        context.record_area(self.id, area);
+        let (area_title, area_content) = MainView::pane_areas(area, 0);
+
+        MainView::draw_pane_title(context, area_title, "[V]ersions [D]iff", &[self]);
+
+        let main_state = self.main_state.borrow();
+        let Some(opened_record) = main_state.opened_record() else {
+            return Ok(());
+        };
+        let focused = context.focused_id() == self.id;
+        let selected_index = self.get_selected_index(opened_record);
+        let last_index = opened_record.versions.len() - 1;
+        // Scrolled just enough for the selected version to stay visible.
+        let scroll_offset =
+            (last_index - selected_index + 1).saturating_sub(area_content.height as usize);
+        let rows = opened_record
+            .versions
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(scroll_offset);
+
+        for (y, (index, listed_version)) in (area_content.y..area_content.bottom()).zip(rows) {
+            let mut line = Self::get_version_line(listed_version, index == last_index);
+
+            if index == opened_record.version_index {
+                line = line.bold();
+            }
+
+            if focused && index == selected_index {
+                line = line.reversed();
+            }
+
+            context.frame().render_widget(
+                line,
+                Rect {
+                    y,
+                    height: 1,
+                    ..area_content
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,550 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use rrr::cbor;
+use rrr::record::{
+    HashedRecordKey, Record, RecordKey, RecordMetadata, RecordName, RecordVersion, SuccessionNonce,
+};
+use rrr::utils::serde::BytesOrAscii;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, info_span};
+
+use crate::action::{Action, ComponentMessage, LoadedRecordVersion};
+use crate::color::{ColorOklch, TextColor};
+use crate::component::{
+    Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints,
+};
+use crate::tui::Event;
+
+use super::button::Button;
+use super::encoding::Encoding;
+use super::input_field::InputField;
+use super::main_view::{
+    MainState, MainView, PaneBottomArgs, get_status_finished, get_status_in_progress,
+};
+use super::open_status::{OpenStatus, SpinnerContent};
+use super::pane_open::PaneOpen;
+use super::radio_array::RadioArray;
+
+/// What a record version is being written to.
+#[derive(Debug, Clone)]
+pub enum WriteTarget {
+    /// A new record, found among the successors of `parent`, or among the top-level records.
+    SubRecord { parent: Option<HashedRecordKey> },
+    /// A new version of an existing record, following `latest_version`.
+    Version {
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<HashedRecordKey>,
+        latest_version: LoadedRecordVersion,
+    },
+}
+
+/// A form for writing a new record version, replacing the content pane while open.
+#[derive(Debug)]
+pub struct PaneWrite {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    main_state: Rc<RefCell<MainState>>,
+    /// Set while the form is open.
+    target: Option<WriteTarget>,
+    record_name_field: InputField,
+    /// The encoding of the record name, independent of that of the content.
+    record_name_encoding_radio_array: RadioArray<Encoding>,
+    content_field: InputField,
+    content_encoding_radio_array: RadioArray<Encoding>,
+    metadata_field: InputField,
+    status_spinner: OpenStatus<'static>,
+    button: Button,
+}
+
+impl PaneWrite {
+    pub fn new(
+        id: ComponentId,
+        action_tx: &UnboundedSender<Action>,
+        main_state: &Rc<RefCell<MainState>>,
+    ) -> Self {
+        Self {
+            id,
+            action_tx: action_tx.clone(),
+            main_state: main_state.clone(),
+            target: None,
+            record_name_field: InputField::new(ComponentId::new(), action_tx),
+            record_name_encoding_radio_array: RadioArray::new(
+                ComponentId::new(),
+                action_tx,
+                vec![Encoding::Utf8, Encoding::Hex],
+                &Encoding::Utf8,
+                Direction::Horizontal,
+            ),
+            content_field: InputField::new(ComponentId::new(), action_tx),
+            content_encoding_radio_array: RadioArray::new(
+                ComponentId::new(),
+                action_tx,
+                vec![Encoding::Utf8, Encoding::Hex],
+                &Encoding::Utf8,
+                Direction::Horizontal,
+            ),
+            metadata_field: InputField::new(ComponentId::new(), action_tx),
+            status_spinner: OpenStatus::new(
+                ComponentId::new(),
+                action_tx,
+                SpinnerContent::default(),
+            ),
+            button: Button::new(ComponentId::new(), action_tx, "Save".into())
+                .with_form_confirmation(true)
+                .with_text_color_unfocused(TextColor::default().bg(ColorOklch::new(0.2, 0.0, 0.0)))
+                .with_text_color_focused(TextColor::default().bg(ColorOklch::new(0.4, 0.0, 0.0)))
+                .with_text_color_pressed(TextColor::default().bg(ColorOklch::new(0.3, 0.0, 0.0))),
+        }
+    }
+
+    pub(super) fn is_open(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Opens the form for writing to `target`, returning the action focusing its first field.
+    pub(super) fn open(&mut self, target: WriteTarget) -> Action {
+        self.record_name_field.reset_content();
+        self.metadata_field.reset_content();
+        self.status_spinner.content = SpinnerContent::default();
+
+        // A new version starts out with the data of the latest one. If the selected encoding
+        // cannot represent it, the first one that can is selected instead, so that saving
+        // without edits keeps the data intact. Hex can represent any data.
+        match &target {
+            WriteTarget::Version { latest_version, .. } => {
+                let selected_encoding = self.content_encoding_radio_array.get_checked().clone();
+                let (encoding, content) = [selected_encoding, Encoding::Utf8, Encoding::Hex]
+                    .into_iter()
+                    .find_map(|encoding| {
+                        let content = encoding.encode(&latest_version.record.data)?;
+                        Some((encoding, content))
+                    })
+                    .expect("hex can represent any data");
+
+                self.content_encoding_radio_array.set_checked(&encoding);
+                self.content_field.set_content(content);
+            }
+            WriteTarget::SubRecord { .. } => self.content_field.reset_content(),
+        }
+
+        let focused_field = match &target {
+            WriteTarget::SubRecord { .. } => &self.record_name_field,
+            WriteTarget::Version { .. } => &self.content_field,
+        };
+        let action = Action::FocusComponent(focused_field.get_id());
+
+        self.target = Some(target);
+        action
+    }
+
+    fn close(&mut self) {
+        self.target = None;
+        self.button.held_down = false;
+    }
+
+    /// Builds the record to save from the form. New versions keep the metadata of the latest
+    /// version, with the entered entries added or replaced.
+    fn get_record(&self, target: &WriteTarget) -> Result<Record> {
+        let data = self
+            .content_encoding_radio_array
+            .get_checked()
+            .decode(self.content_field.get_content())
+            .wrap_err("Invalid content")?;
+        let mut metadata = match target {
+            WriteTarget::SubRecord { .. } => RecordMetadata::default(),
+            WriteTarget::Version { latest_version, .. } => latest_version.record.metadata.clone(),
+        };
+        let metadata_input = self.metadata_field.get_content().trim();
+
+        if !metadata_input.is_empty() {
+            let serde_json::Value::Object(entries) =
+                serde_json::from_str(metadata_input).wrap_err("The metadata is not valid JSON")?
+            else {
+                return Err(eyre!("The metadata must be a JSON object"));
+            };
+
+            for (key, value) in &entries {
+                metadata.insert(
+                    cbor::Value::Text(key.clone()),
+                    crate::cbor::json_to_cbor_value(value),
+                );
+            }
+        }
+
+        Ok(Record { metadata, data })
+    }
+
+    fn spawn_save_task(&mut self) {
+        let Some(target) = self.target.clone() else {
+            return;
+        };
+        let prepared = self.get_record(&target).and_then(|record| {
+            let record_name = match &target {
+                WriteTarget::SubRecord { .. } => {
+                    let bytes = self
+                        .record_name_encoding_radio_array
+                        .get_checked()
+                        .decode(self.record_name_field.get_content())
+                        .wrap_err("Invalid record name")?;
+                    BytesOrAscii(bytes)
+                }
+                WriteTarget::Version { record_name, .. } => record_name.clone(),
+            };
+
+            Ok((record, record_name))
+        });
+        let (record, record_name) = match prepared {
+            Ok(prepared) => prepared,
+            Err(error) => {
+                self.status_spinner.content =
+                    get_status_finished(format!("{error:#}").into(), 29.23 / 360.0);
+                return;
+            }
+        };
+        // The saved record is opened afterwards, superseding any earlier request to open a record.
+        let open_request = self.main_state.borrow_mut().begin_open_request();
+        // Cloned for the same reason as in `PaneOpen::spawn_open_record_task_with_record_name`.
+        let main_state_clone = self.main_state.borrow().clone();
+        let action_tx = self.action_tx.clone();
+
+        self.status_spinner.content = get_status_in_progress(" Saving… ".into());
+
+        tokio::spawn(
+            async move {
+                let result = Self::save_record(
+                    &action_tx,
+                    &main_state_clone,
+                    open_request,
+                    target,
+                    &record,
+                    record_name,
+                )
+                .await;
+
+                // Fails only if the app is quitting, in which case the result is of no interest.
+                let _ = action_tx.send(Action::BroadcastMessage(ComponentMessage::RecordSave {
+                    result: result.map_err(|error| error.to_string()),
+                }));
+            }
+            .instrument(info_span!("save record task")),
+        );
+    }
+
+    /// Saves `record` as the version following the latest one, then opens the saved record.
+    /// Fails without writing anything if the latest version is not the one the form was opened
+    /// for, as the record would otherwise be modified based on outdated contents.
+    async fn save_record(
+        action_tx: &UnboundedSender<Action>,
+        main_state: &MainState,
+        (generation, cancellation_token): (u64, CancellationToken),
+        target: WriteTarget,
+        record: &Record,
+        record_name: RecordName,
+    ) -> Result<RecordVersion> {
+        let (hashed_record_key, parent, expected_latest_version) = match target {
+            WriteTarget::SubRecord { parent } => {
+                let current_succession_nonce = main_state
+                    .get_succession_nonce(action_tx, parent.as_ref())
+                    .await?;
+                // The key owns its copy of the nonce, which is dropped right after hashing.
+                let record_key = RecordKey {
+                    predecessor_nonce: SuccessionNonce::clone(&current_succession_nonce),
+                    record_name: record_name.clone(),
+                };
+                let hashed_record_key = record_key
+                    .hash(&main_state.registry.read().await.config().hash)
+                    .await?;
+
+                (hashed_record_key, parent, None)
+            }
+            WriteTarget::Version {
+                hashed_record_key,
+                parent,
+                latest_version,
+                ..
+            } => (
+                hashed_record_key,
+                parent,
+                Some(latest_version.record_version),
+            ),
+        };
+        let mut registry = main_state.registry.write().await;
+        let latest_version = registry
+            .list_record_versions(
+                &hashed_record_key,
+                main_state.search_limits.max_version_lookahead,
+                main_state.search_limits.max_collision_resolution_attempts,
+            )
+            .await?
+            .last()
+            .copied();
+
+        if latest_version != expected_latest_version {
+            return Err(match (expected_latest_version, latest_version) {
+                (None, _) => eyre!("A record with this name already exists"),
+                (Some(_), Some(latest_version)) => eyre!(
+                    "A newer version v{latest_version} appeared, reopen the record and try again"
+                ),
+                (Some(_), None) => eyre!("The record no longer exists"),
+            });
+        }
+
+        let record_version = latest_version.map_or(0, |latest_version| latest_version + 1);
+
+        registry
+            .save_record(
+                &hashed_record_key,
+                record,
+                record_version,
+                main_state.search_limits.max_collision_resolution_attempts,
+            )
+            .await?;
+        drop(registry);
+
+        // The record is no longer reopened once another one is requested to be opened.
+        tokio::select! {
+            () = cancellation_token.cancelled() => {}
+            result = PaneOpen::open_record(
+                action_tx,
+                &main_state.registry,
+                main_state.search_limits,
+                generation,
+                hashed_record_key,
+                record_name,
+                parent,
+            ) => result?,
+        }
+
+        Ok(record_version)
+    }
+}
+
+impl Component for PaneWrite {
+    fn update(&mut self, message: ComponentMessage) -> Result<Option<Action>> {
+        match message {
+            ComponentMessage::OnButtonPress { id } if id == self.button.get_id() => {
+                self.spawn_save_task();
+                Ok(Some(Action::Render))
+            }
+            ComponentMessage::RecordSave { result } if self.is_open() => {
+                match result {
+                    Ok(_) => self.close(),
+                    Err(error) => {
+                        self.status_spinner.content =
+                            get_status_finished(error.into(), 29.23 / 360.0)
+                    }
+                }
+                Ok(Some(Action::Render))
+            }
+            // The record being written to is no longer displayed.
+            ComponentMessage::RecordOpen { .. } | ComponentMessage::Navigate(_)
+                if self.is_open() =>
+            {
+                self.close();
+                Ok(Some(Action::Render))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        match event {
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => {
+                self.spawn_save_task();
+                self.button.held_down = true;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Release,
+                ..
+            })
+            | Event::FocusLost => {
+                self.button.held_down = false;
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                kind: KeyEventKind::Press,
+                ..
+            }) => {
+                self.close();
+                Ok(HandleEventSuccess::handled().with_action(Action::Render))
+            }
+            _ => Ok(HandleEventSuccess::unhandled()),
+        }
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Write")
+                .with_hint("<Enter>", "Save the record")
+                .with_hint("<Esc>", "Cancel"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn get_children(&self) -> Vec<&dyn Component> {
+        let mut children: Vec<&dyn Component> = Vec::new();
+
+        if let Some(WriteTarget::SubRecord { .. }) = self.target {
+            children.extend([
+                &self.record_name_field as &dyn Component,
+                &self.record_name_encoding_radio_array,
+            ]);
+        }
+
+        children.extend([
+            &self.content_field as &dyn Component,
+            &self.content_encoding_radio_array,
+            &self.metadata_field,
+            &self.status_spinner,
+            &self.button,
+        ]);
+        children
+    }
+
+    fn get_children_mut(&mut self) -> Vec<&mut dyn Component> {
+        let mut children: Vec<&mut dyn Component> = Vec::new();
+
+        if let Some(WriteTarget::SubRecord { .. }) = self.target {
+            children.extend([
+                &mut self.record_name_field as &mut dyn Component,
+                &mut self.record_name_encoding_radio_array,
+            ]);
+        }
+
+        children.extend([
+            &mut self.content_field as &mut dyn Component,
+            &mut self.content_encoding_radio_array,
+            &mut self.metadata_field,
+            &mut self.status_spinner,
+            &mut self.button,
+        ]);
+        children
+    }
+}
+
+impl Drawable for PaneWrite {
+    type Args<'a>
+        = PaneBottomArgs
+    where
+        Self: 'a;
+
+    fn draw<'a>(
+        &self,
+        context: &mut DrawContext,
+        area: Rect,
+        extra_args: Self::Args<'a>,
+    ) -> Result<()>
+    where
+        Self: 'a,
+    {
+        let Some(target) = self.target.as_ref() else {
+            return Ok(());
+        };
+        context.record_area(self.id, area);
+        let (area_title, area_content) = MainView::pane_areas(area, extra_args.title_offset_x);
+        let title = match target {
+            WriteTarget::SubRecord { parent: None } => "New Top-Level Record".to_string(),
+            WriteTarget::SubRecord { parent: Some(_) } => "New Sub-Record".to_string(),
+            WriteTarget::Version { latest_version, .. } => {
+                format!("New Version v{}", latest_version.record_version + 1)
+            }
+        };
+
+        MainView::draw_pane_title(
+            context,
+            area_title,
+            format!("{title} [Enter] to save [Esc] to cancel"),
+            &[self],
+        );
+
+        let layout_lines = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([Constraint::Length(11), Constraint::Fill(1)]);
+        let record_name_height = if matches!(target, WriteTarget::SubRecord { .. }) {
+            1
+        } else {
+            0
+        };
+        let [
+            area_record_name,
+            area_record_name_encoding,
+            area_content_line,
+            area_content_encoding,
+            area_metadata,
+            _,
+            area_footer,
+        ] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(record_name_height),
+                Constraint::Length(record_name_height),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(area_content);
+        let [area_status, area_button] = Layout::default()
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .constraints([Constraint::Fill(1), Constraint::Length(18)])
+            .areas(area_footer);
+
+        for (label, area_line, field) in [
+            ("Record Name", area_record_name, &self.record_name_field),
+            ("Content", area_content_line, &self.content_field),
+            ("Metadata", area_metadata, &self.metadata_field),
+        ] {
+            if area_line.height == 0 {
+                continue;
+            }
+
+            let [area_label, area_field] = layout_lines.areas(area_line);
+
+            context.frame().render_widget(Span::raw(label), area_label);
+            field.draw(context, area_field, ())?;
+        }
+
+        for (area_line, radio_array) in [
+            (
+                area_record_name_encoding,
+                &self.record_name_encoding_radio_array,
+            ),
+            (area_content_encoding, &self.content_encoding_radio_array),
+        ] {
+            if area_line.height == 0 {
+                continue;
+            }
+
+            let [area_label, area_field] = layout_lines.areas(area_line);
+
+            context
+                .frame()
+                .render_widget(Span::raw("Encoding"), area_label);
+            radio_array.draw(context, area_field, ())?;
+        }
+        self.status_spinner.draw(context, area_status, ())?;
+        self.button.draw(context, area_button, ())?;
+
+        Ok(())
+    }
+}
@@ -66,6 +66,20 @@ where
         &self.items[self.checked_index].0
     }
 
+    /// Checks the item equal to `checked_item`, if there is one.
+    pub fn set_checked(&mut self, checked_item: &T) {
+        let Some(checked_index) = self.items.iter().position(|(item, _)| item == checked_item)
+        else {
+            return;
+        };
+
+        for (index, (_, checkbox)) in self.items.iter_mut().enumerate() {
+            checkbox.checked = index == checked_index;
+        }
+
+        self.checked_index = checked_index;
+    }
+
     #[expect(unused)]
     pub fn get_checked_mut(&mut self) -> &mut T {
         &mut self.items[self.checked_index].0
@@ -162,6 +176,8 @@ where
             return Ok(());
         }
 
+        context.record_area(self.id, area);
+
         let (areas, _) = Layout::new(
             self.layout_direction,
             self.items.iter().map(|(_, checkbox)| {
@@ -0,0 +1,412 @@
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind};
+use itertools::Itertools;
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use rrr::record::{HashedRecordKey, RECORD_NAME_ROOT, RecordName};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, ComponentMessage},
+    color::{ColorOklch, TextColor},
+    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints},
+    tui::Event,
+};
+
+use super::scroll_view::MOUSE_SCROLL_ROWS;
+
+/// Formats a record name for display, preferring text and falling back to hexadecimal.
+pub fn format_record_name(record_name: &RecordName) -> String {
+    if let Ok(text) = std::str::from_utf8(&record_name.0)
+        && !text.is_empty()
+        && !text.chars().any(char::is_control)
+    {
+        text.into()
+    } else if record_name.0.is_empty() {
+        "(empty)".into()
+    } else {
+        format!("0x{:02x}", record_name.0.iter().format(""))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RecordTreeNode {
+    hashed_record_key: HashedRecordKey,
+    record_name: RecordName,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    expanded: bool,
+}
+
+impl RecordTreeNode {
+    /// Returns `true` iff this is the root record of the registry.
+    fn is_root(&self) -> bool {
+        self.parent.is_none() && self.record_name == RECORD_NAME_ROOT
+    }
+
+    fn label(&self) -> String {
+        if self.is_root() {
+            "(root)".into()
+        } else {
+            format_record_name(&self.record_name)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VisibleRow {
+    node_index: usize,
+    prefix: String,
+}
+
+/// A tree of all records opened during this session, linked by their predecessors.
+#[derive(Debug)]
+pub struct RecordTree {
+    id: ComponentId,
+    action_tx: UnboundedSender<Action>,
+    /// All nodes, indexed by the `usize` references within the nodes.
+    nodes: Vec<RecordTreeNode>,
+    /// Nodes of records opened without a predecessor record.
+    roots: Vec<usize>,
+    selected: Option<usize>,
+    opened: Option<usize>,
+}
+
+impl RecordTree {
+    pub fn new(id: ComponentId, action_tx: &UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        Self {
+            id,
+            action_tx: action_tx.clone(),
+            nodes: Default::default(),
+            roots: Default::default(),
+            selected: None,
+            opened: None,
+        }
+    }
+
+    fn find(&self, hashed_record_key: &HashedRecordKey) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.hashed_record_key == *hashed_record_key)
+    }
+
+    /// Inserts the record unless it is already present, returning the index of its node.
+    fn insert(
+        &mut self,
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<&HashedRecordKey>,
+    ) -> usize {
+        if let Some(index) = self.find(&hashed_record_key) {
+            return index;
+        }
+
+        let parent = parent.and_then(|parent| self.find(parent));
+        let index = self.nodes.len();
+
+        self.nodes.push(RecordTreeNode {
+            hashed_record_key,
+            record_name,
+            parent,
+            children: Default::default(),
+            expanded: true,
+        });
+
+        match parent {
+            Some(parent) => self.nodes[parent].children.push(index),
+            None => self.roots.push(index),
+        }
+
+        index
+    }
+
+    /// Expands all ancestors of the node, so that it becomes visible.
+    fn reveal(&mut self, index: usize) {
+        let mut current = self.nodes[index].parent;
+
+        while let Some(ancestor) = current {
+            self.nodes[ancestor].expanded = true;
+            current = self.nodes[ancestor].parent;
+        }
+    }
+
+    fn visible_rows(&self) -> Vec<VisibleRow> {
+        let mut rows = Vec::new();
+
+        for &root in &self.roots {
+            let node = &self.nodes[root];
+            let marker = if node.children.is_empty() {
+                "─"
+            } else if node.expanded {
+                "┌"
+            } else {
+                "▸"
+            };
+
+            rows.push(VisibleRow {
+                node_index: root,
+                prefix: marker.into(),
+            });
+
+            if node.expanded {
+                self.collect_visible_rows(&node.children, &mut String::new(), &mut rows);
+            }
+        }
+
+        rows
+    }
+
+    fn collect_visible_rows(
+        &self,
+        siblings: &[usize],
+        indent: &mut String,
+        rows: &mut Vec<VisibleRow>,
+    ) {
+        for (position, &node_index) in siblings.iter().enumerate() {
+            let node = &self.nodes[node_index];
+            let last = position + 1 == siblings.len();
+            let connector = if last { "└" } else { "├" };
+            let marker = if node.children.is_empty() {
+                "─"
+            } else if node.expanded {
+                "┬"
+            } else {
+                "▸"
+            };
+
+            rows.push(VisibleRow {
+                node_index,
+                prefix: format!("{indent}{connector}{marker}"),
+            });
+
+            if node.expanded {
+                indent.push(if last { ' ' } else { '│' });
+                self.collect_visible_rows(&node.children, indent, rows);
+                indent.pop();
+            }
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let rows = self.visible_rows();
+        let Some(current_row) = self
+            .selected
+            .and_then(|selected| rows.iter().position(|row| row.node_index == selected))
+        else {
+            self.selected = rows.first().map(|row| row.node_index);
+            return;
+        };
+        let new_row = current_row
+            .saturating_add_signed(delta)
+            .min(rows.len().saturating_sub(1));
+
+        self.selected = Some(rows[new_row].node_index);
+    }
+
+    fn expand_or_descend(&mut self) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let node = &mut self.nodes[selected];
+
+        if !node.expanded {
+            node.expanded = true;
+        } else if let Some(&first_child) = node.children.first() {
+            self.selected = Some(first_child);
+        }
+    }
+
+    fn collapse_or_ascend(&mut self) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let node = &mut self.nodes[selected];
+
+        if node.expanded && !node.children.is_empty() {
+            node.expanded = false;
+        } else if let Some(parent) = node.parent {
+            self.selected = Some(parent);
+        }
+    }
+
+    /// Marks the record as the opened one, and selects it.
+    pub fn set_opened(&mut self, hashed_record_key: &HashedRecordKey) {
+        if let Some(index) = self.find(hashed_record_key) {
+            self.reveal(index);
+            self.opened = Some(index);
+            self.selected = Some(index);
+        }
+    }
+
+    /// Inserts the newly opened record unless it is already present, then marks it as the opened
+    /// one and selects it.
+    pub fn insert_opened(
+        &mut self,
+        hashed_record_key: HashedRecordKey,
+        record_name: RecordName,
+        parent: Option<&HashedRecordKey>,
+    ) {
+        let index = self.insert(hashed_record_key, record_name, parent);
+
+        self.reveal(index);
+        self.opened = Some(index);
+        self.selected = Some(index);
+    }
+
+    /// Returns the labels of the record's ancestors and the record itself, starting with the
+    /// top-level record. Each label is paired with whether it is the root record of the registry.
+    pub fn get_path_labels(&self, hashed_record_key: &HashedRecordKey) -> Vec<(String, bool)> {
+        let mut labels = Vec::new();
+        let mut current = self.find(hashed_record_key);
+
+        while let Some(index) = current {
+            let node = &self.nodes[index];
+
+            labels.push((node.label(), node.is_root()));
+            current = self.nodes[index].parent;
+        }
+
+        labels.reverse();
+        labels
+    }
+
+    fn reopen_selected(&self) -> Result<()> {
+        let Some(selected) = self.selected else {
+            return Ok(());
+        };
+        let node = &self.nodes[selected];
+
+        self.action_tx
+            .send(Action::BroadcastMessage(ComponentMessage::RecordReopen {
+                hashed_record_key: node.hashed_record_key.clone(),
+                record_name: node.record_name.clone(),
+                parent: node
+                    .parent
+                    .map(|parent| self.nodes[parent].hashed_record_key.clone()),
+            }))?;
+
+        Ok(())
+    }
+}
+
+impl Component for RecordTree {
+    fn is_focusable(&self) -> bool {
+        !self.nodes.is_empty()
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        if let Event::Mouse(MouseEvent { kind, .. }) = event {
+            match kind {
+                MouseEventKind::ScrollUp => self.move_selection(-MOUSE_SCROLL_ROWS),
+                MouseEventKind::ScrollDown => self.move_selection(MOUSE_SCROLL_ROWS),
+                _ => return Ok(HandleEventSuccess::unhandled()),
+            }
+
+            return Ok(HandleEventSuccess::handled().with_action(Action::Render));
+        }
+
+        let Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event
+        else {
+            return Ok(HandleEventSuccess::unhandled());
+        };
+
+        match code {
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Right => self.expand_or_descend(),
+            KeyCode::Left => self.collapse_or_ascend(),
+            KeyCode::Char(' ') => {
+                if let Some(selected) = self.selected {
+                    self.nodes[selected].expanded = !self.nodes[selected].expanded;
+                }
+            }
+            KeyCode::Enter => self.reopen_selected()?,
+            _ => return Ok(HandleEventSuccess::unhandled()),
+        }
+
+        Ok(HandleEventSuccess::handled().with_action(Action::Render))
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Record Tree")
+                .with_hint("<Up> <Down>", "Select the previous or next record")
+                .with_hint("<Right>", "Expand or select the first sub-record")
+                .with_hint("<Left>", "Collapse or select the parent record")
+                .with_hint("<Space>", "Expand or collapse")
+                .with_hint("<Enter>", "Open the selected record"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+}
+
+impl Drawable for RecordTree {
+    type Args<'a>
+        = ()
+    where
+        Self: 'a;
+
+    fn draw<'a>(&self, context: &mut DrawContext, area: Rect, (): Self::Args<'a>) -> Result<()>
+    where
+        Self: 'a,
+    {
+        if area.area() == 0 {
+            return Ok(());
+        }
+
+        context.record_area(self.id, area);
+        let focused = context.focused_id() == self.id;
+        let rows = self.visible_rows();
+        let selected_row = self
+            .selected
+            .and_then(|selected| rows.iter().position(|row| row.node_index == selected))
+            .unwrap_or(0);
+        // Scroll just enough for the selected row to be visible.
+        let first_row = selected_row.saturating_sub(area.height as usize - 1);
+
+        for (y, row) in (area.y..(area.y + area.height)).zip(rows.iter().skip(first_row)) {
+            let node = &self.nodes[row.node_index];
+            let mut label_style = Style::new();
+
+            if Some(row.node_index) == self.opened {
+                label_style = label_style.bold();
+            }
+
+            if focused && Some(row.node_index) == self.selected {
+                label_style = label_style.reversed();
+            }
+
+            let line = Line::from_iter([
+                Span::styled(
+                    row.prefix.as_str(),
+                    TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0)),
+                ),
+                Span::styled(node.label(), label_style),
+            ]);
+
+            context.frame().render_widget(
+                line,
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
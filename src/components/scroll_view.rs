@@ -0,0 +1,249 @@
+use std::cell::Cell;
+
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    text::Text,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, Widget},
+};
+
+use crate::{
+    action::Action,
+    component::{Component, ComponentId, DrawContext, Drawable, HandleEventSuccess, KeyHints},
+    tui::Event,
+};
+
+/// The number of rows scrolled by a single step of the mouse wheel.
+pub const MOUSE_SCROLL_ROWS: isize = 3;
+
+/// Content that can be displayed within a `ScrollView`.
+pub trait ScrollContent {
+    /// The number of rows the content occupies.
+    fn height(&self) -> usize;
+
+    /// Renders the rows of the content starting with the row at `offset` into `area`.
+    fn render_scrolled(self: Box<Self>, area: Rect, buf: &mut Buffer, offset: usize);
+}
+
+impl ScrollContent for Text<'_> {
+    fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn render_scrolled(mut self: Box<Self>, area: Rect, buf: &mut Buffer, offset: usize) {
+        self.lines = std::mem::take(&mut self.lines)
+            .into_iter()
+            .skip(offset)
+            .take(area.height as usize)
+            .collect();
+        self.render(area, buf);
+    }
+}
+
+/// Borrowed text, such as formatted content cached across draws.
+impl ScrollContent for &Text<'_> {
+    fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn render_scrolled(self: Box<Self>, area: Rect, buf: &mut Buffer, offset: usize) {
+        buf.set_style(area, self.style);
+
+        for (y, line) in (area.y..area.bottom()).zip(self.lines.iter().skip(offset)) {
+            line.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+}
+
+pub struct ScrollViewArgs<'a> {
+    pub content: Box<dyn ScrollContent + 'a>,
+    /// A row to scroll to, unless it is visible already.
+    pub revealed_row: Option<usize>,
+}
+
+impl<'a> ScrollViewArgs<'a> {
+    pub fn new(content: impl ScrollContent + 'a) -> Self {
+        Self {
+            content: Box::new(content),
+            revealed_row: None,
+        }
+    }
+
+    pub fn with_revealed_row(mut self, revealed_row: usize) -> Self {
+        self.revealed_row = Some(revealed_row);
+        self
+    }
+}
+
+/// A vertically scrollable viewport with a scrollbar, displaying any `ScrollContent`.
+#[derive(Debug)]
+pub struct ScrollView {
+    id: ComponentId,
+    /// The index of the first visible row.
+    offset: Cell<usize>,
+    /// The heights of the viewport and the content, as of the last draw.
+    viewport_height: Cell<u16>,
+    content_height: Cell<usize>,
+}
+
+impl ScrollView {
+    pub fn new(id: ComponentId) -> Self {
+        Self {
+            id,
+            offset: Default::default(),
+            viewport_height: Default::default(),
+            content_height: Default::default(),
+        }
+    }
+
+    /// The width available to the content, excluding the scrollbar.
+    pub fn get_content_width(area: Rect) -> u16 {
+        area.width.saturating_sub(1)
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.offset.set(0);
+    }
+
+    fn get_max_offset(&self) -> usize {
+        self.content_height
+            .get()
+            .saturating_sub(self.viewport_height.get() as usize)
+    }
+
+    fn scroll_by(&mut self, delta: isize) {
+        let offset = self
+            .offset
+            .get()
+            .saturating_add_signed(delta)
+            .min(self.get_max_offset());
+
+        self.offset.set(offset);
+    }
+}
+
+impl Component for ScrollView {
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Result<HandleEventSuccess> {
+        if let Event::Mouse(MouseEvent { kind, .. }) = event {
+            match kind {
+                MouseEventKind::ScrollUp => self.scroll_by(-MOUSE_SCROLL_ROWS),
+                MouseEventKind::ScrollDown => self.scroll_by(MOUSE_SCROLL_ROWS),
+                _ => return Ok(HandleEventSuccess::unhandled()),
+            }
+
+            return Ok(HandleEventSuccess::handled().with_action(Action::Render));
+        }
+
+        let Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event
+        else {
+            return Ok(HandleEventSuccess::unhandled());
+        };
+        let page = std::cmp::max(1, self.viewport_height.get().saturating_sub(1)) as isize;
+
+        match code {
+            KeyCode::Up => self.scroll_by(-1),
+            KeyCode::Down => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-page),
+            KeyCode::PageDown => self.scroll_by(page),
+            KeyCode::Home => self.offset.set(0),
+            KeyCode::End => self.offset.set(self.get_max_offset()),
+            _ => return Ok(HandleEventSuccess::unhandled()),
+        }
+
+        Ok(HandleEventSuccess::handled().with_action(Action::Render))
+    }
+
+    fn get_key_hints(&self) -> Option<KeyHints> {
+        Some(
+            KeyHints::new("Scrolling")
+                .with_hint("<Up> <Down>", "Scroll by a row")
+                .with_hint("<PageUp> <PageDown>", "Scroll by a page")
+                .with_hint("<Home> <End>", "Scroll to the top or the bottom"),
+        )
+    }
+
+    fn get_id(&self) -> ComponentId {
+        self.id
+    }
+}
+
+impl Drawable for ScrollView {
+    type Args<'a>
+        = ScrollViewArgs<'a>
+    where
+        Self: 'a;
+
+    fn draw<'a>(
+        &self,
+        context: &mut DrawContext,
+        area: Rect,
+        ScrollViewArgs {
+            content,
+            revealed_row,
+        }: Self::Args<'a>,
+    ) -> Result<()>
+    where
+        Self: 'a,
+    {
+        if area.area() == 0 {
+            return Ok(());
+        }
+
+        context.record_area(self.id, area);
+        self.viewport_height.set(area.height);
+        self.content_height.set(content.height());
+
+        if let Some(revealed_row) = revealed_row {
+            let offset = self.offset.get();
+
+            if revealed_row < offset {
+                self.offset.set(revealed_row);
+            } else if revealed_row >= offset + area.height as usize {
+                self.offset.set(revealed_row + 1 - area.height as usize);
+            }
+        }
+
+        // The content may have shrunk since the last draw.
+        let max_offset = self.get_max_offset();
+        let offset = self.offset.get().min(max_offset);
+        self.offset.set(offset);
+
+        let [area_content, area_scrollbar] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+
+        content.render_scrolled(area_content, context.frame().buffer_mut(), offset);
+
+        if max_offset > 0 {
+            let mut scrollbar_state = ScrollbarState::new(max_offset + 1)
+                .position(offset)
+                .viewport_content_length(area.height as usize);
+
+            context.frame().render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None),
+                area_scrollbar,
+                &mut scrollbar_state,
+            );
+        }
+
+        Ok(())
+    }
+}
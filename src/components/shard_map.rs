@@ -0,0 +1,93 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+use crate::{
+    color::{ColorOklch, TextColor},
+    registry::RegistryShards,
+};
+
+/// The state of a single pixel of the map, where a pixel is half of a terminal cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Pixel {
+    Empty,
+    Available,
+    Visited,
+}
+
+impl Pixel {
+    fn color(self) -> Option<ColorOklch> {
+        match self {
+            Self::Empty => None,
+            Self::Available => Some(ColorOklch::new(0.4, 0.0, 0.0)),
+            Self::Visited => Some(ColorOklch::new(0.79, 0.1603, 153.29 / 360.0)),
+        }
+    }
+}
+
+/// Draws each shard of the registry as a pixel of a half-block map, filled row by row in the
+/// order of the fragment file paths. Visited shards are highlighted.
+/// If there are more shards than pixels, each pixel represents several shards and is highlighted
+/// if any of them was visited.
+pub struct ShardMap<'a> {
+    pub shards: &'a RegistryShards,
+}
+
+impl ShardMap<'_> {
+    /// Assigns the shards to the pixels of a map `width` pixels wide and at most `max_height`
+    /// pixels high, returning them row by row. There must be at least one shard and one pixel.
+    fn get_pixels(&self, width: usize, max_height: usize) -> Vec<Pixel> {
+        let shard_count = self.shards.available.len();
+        let pixel_count = shard_count.min(width * max_height);
+        let mut pixels = vec![Pixel::Empty; pixel_count.div_ceil(width) * width];
+
+        for (index, path) in self.shards.available.iter().enumerate() {
+            let pixel = if self.shards.visited.contains(path) {
+                Pixel::Visited
+            } else {
+                Pixel::Available
+            };
+            let pixel_index = index * pixel_count / shard_count;
+
+            pixels[pixel_index] = pixels[pixel_index].max(pixel);
+        }
+
+        pixels
+    }
+}
+
+impl Widget for ShardMap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        if area.area() == 0 || self.shards.available.is_empty() {
+            return;
+        }
+
+        let width = area.width as usize;
+        let pixels = self.get_pixels(width, 2 * area.height as usize);
+
+        for (row_index, rows) in pixels.chunks(2 * width).enumerate() {
+            let (upper, lower) = rows.split_at(width.min(rows.len()));
+
+            for (column_index, upper) in upper.iter().enumerate() {
+                let lower = lower.get(column_index).copied().unwrap_or(Pixel::Empty);
+                let (symbol, fg, bg) = match (upper.color(), lower.color()) {
+                    (None, None) => continue,
+                    (Some(upper), None) => ("▀", upper, None),
+                    (None, Some(lower)) => ("▄", lower, None),
+                    (Some(color), Some(_)) if *upper == lower => ("█", color, None),
+                    (Some(upper), Some(lower)) => ("▀", upper, Some(lower)),
+                };
+                let mut color = TextColor::default().fg(fg);
+
+                if let Some(bg) = bg {
+                    color = color.bg(bg);
+                }
+
+                buf[(area.x + column_index as u16, area.y + row_index as u16)]
+                    .set_symbol(symbol)
+                    .set_style(Style::from(&color));
+            }
+        }
+    }
+}
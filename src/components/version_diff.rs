@@ -0,0 +1,202 @@
+use itertools::EitherOrBoth;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use crate::{
+    action::LoadedRecordVersion,
+    cbor::{record_metadata_keys_eq, record_metadata_to_line},
+    color::{ColorOklch, TextColor},
+    diff::{DiffOp, diff_lines, side_by_side},
+};
+
+use super::scroll_view::ScrollContent;
+
+fn color_removed() -> Style {
+    TextColor::default()
+        .fg(ColorOklch::new(0.7, 0.15, 29.23 / 360.0))
+        .into()
+}
+
+fn color_added() -> Style {
+    TextColor::default()
+        .fg(ColorOklch::new(0.79, 0.1603, 153.29 / 360.0))
+        .into()
+}
+
+fn color_changed() -> Style {
+    TextColor::default()
+        .fg(ColorOklch::new(0.79, 0.1603, 67.76 / 360.0))
+        .into()
+}
+
+fn color_unchanged() -> Style {
+    TextColor::default()
+        .fg(ColorOklch::new(0.6, 0.0, 0.0))
+        .into()
+}
+
+fn into_owned(line: Line<'_>) -> Line<'static> {
+    Line {
+        spans: line
+            .spans
+            .into_iter()
+            .map(|span| Span::styled(span.content.into_owned(), span.style))
+            .collect(),
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+/// A side-by-side comparison of two versions of the same record, computed once when the versions
+/// are chosen. The metadata is compared key by key, and the data line by line.
+#[derive(Debug)]
+pub struct VersionDiff {
+    rows: Vec<(Line<'static>, Line<'static>)>,
+}
+
+impl VersionDiff {
+    pub fn new(old: &LoadedRecordVersion, new: &LoadedRecordVersion) -> Self {
+        let section_header = |title: &'static str| {
+            let line = Line::styled(title, color_unchanged().italic());
+            (line.clone(), line)
+        };
+        let rows = [(
+            Line::from(format!("v{}", old.record_version)).bold(),
+            Line::from(format!("v{}", new.record_version)).bold(),
+        )]
+        .into_iter()
+        .chain([section_header("Metadata")])
+        .chain(Self::metadata_rows(old, new))
+        .chain([section_header("Data")])
+        .chain(Self::data_rows(old, new))
+        .map(|(old, new)| (into_owned(old), into_owned(new)))
+        .collect();
+
+        Self { rows }
+    }
+
+    fn metadata_rows<'a>(
+        old: &'a LoadedRecordVersion,
+        new: &'a LoadedRecordVersion,
+    ) -> Vec<(Line<'a>, Line<'a>)> {
+        let mut rows = Vec::new();
+        let mut old_entries = old
+            .record
+            .metadata
+            .iter_with_semantic_keys()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        for (new_key, new_value) in new.record.metadata.iter_with_semantic_keys() {
+            let old_entry = old_entries
+                .iter_mut()
+                .find(|old_entry| {
+                    old_entry
+                        .as_ref()
+                        .is_some_and(|(old_key, _)| record_metadata_keys_eq(old_key, &new_key))
+                })
+                .and_then(Option::take);
+
+            rows.push(match old_entry {
+                Some((old_key, old_value)) => {
+                    let style = if old_value == new_value {
+                        color_unchanged()
+                    } else {
+                        color_changed()
+                    };
+
+                    (
+                        record_metadata_to_line(old_key, old_value).patch_style(style),
+                        record_metadata_to_line(new_key, new_value).patch_style(style),
+                    )
+                }
+                None => (
+                    Line::default(),
+                    record_metadata_to_line(new_key, new_value).patch_style(color_added()),
+                ),
+            });
+        }
+
+        rows.extend(
+            old_entries
+                .into_iter()
+                .flatten()
+                .map(|(old_key, old_value)| {
+                    (
+                        record_metadata_to_line(old_key, old_value).patch_style(color_removed()),
+                        Line::default(),
+                    )
+                }),
+        );
+
+        rows
+    }
+
+    fn data_rows<'a>(
+        old: &'a LoadedRecordVersion,
+        new: &'a LoadedRecordVersion,
+    ) -> Vec<(Line<'a>, Line<'a>)> {
+        let (Ok(old_data), Ok(new_data)) = (
+            std::str::from_utf8(&old.record.data),
+            std::str::from_utf8(&new.record.data),
+        ) else {
+            let notice = Line::styled("(not readable as text)", color_unchanged());
+            return vec![(notice.clone(), notice)];
+        };
+        let op_to_line = |op: DiffOp<'a>| match op {
+            DiffOp::Equal(line) => Line::raw(line),
+            DiffOp::Removed(line) => Line::styled(line, color_removed()),
+            DiffOp::Added(line) => Line::styled(line, color_added()),
+        };
+
+        side_by_side(&diff_lines(old_data, new_data))
+            .into_iter()
+            .map(|row| match row {
+                EitherOrBoth::Both(old, new) => (op_to_line(old), op_to_line(new)),
+                EitherOrBoth::Left(old) => (op_to_line(old), Line::default()),
+                EitherOrBoth::Right(new) => (Line::default(), op_to_line(new)),
+            })
+            .collect()
+    }
+}
+
+impl ScrollContent for &VersionDiff {
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn render_scrolled(self: Box<Self>, area: Rect, buf: &mut Buffer, offset: usize) {
+        let [area_old, area_separator, area_new] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        for (y, (old, new)) in (area.y..area.bottom()).zip(self.rows.iter().skip(offset)) {
+            old.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area_old
+                },
+                buf,
+            );
+            new.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area_new
+                },
+                buf,
+            );
+            buf[(area_separator.x, y)].set_symbol(symbols::line::VERTICAL);
+        }
+    }
+}
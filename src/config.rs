@@ -0,0 +1,341 @@
+use std::{collections::BTreeSet, io::ErrorKind, path::PathBuf};
+
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use config::{Environment, File, FileFormat};
+use directories::ProjectDirs;
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use crate::args::Args;
+use crate::components::content_view_mode::ContentViewMode;
+use crate::env::{PKG_NAME, PROJECT_NAME};
+use crate::keybindings::KeyBindings;
+
+/// The built-in defaults, overridden by all other configuration sources.
+const DEFAULT_CONFIG: &str = include_str!("../.config/config.json5");
+
+/// The files looked up in the configuration directory, in the order of increasing precedence.
+const CONFIG_FILES: [(&str, FileFormat); 5] = [
+    ("config.json5", FileFormat::Json5),
+    ("config.json", FileFormat::Json),
+    ("config.yaml", FileFormat::Yaml),
+    ("config.toml", FileFormat::Toml),
+    ("config.ini", FileFormat::Ini),
+];
+
+/// The file in the configuration directory that `Config::save` writes the edited settings into,
+/// taking precedence over the `config.*` files, so that their comments and formatting are left
+/// untouched.
+const SETTINGS_FILE: &str = "settings.json";
+
+lazy_static! {
+    /// The prefix of environment variables overriding configuration keys.
+    /// Nested keys are separated by `__`, e.g. `RRR_TUI_UI__MAX_WIDTH`.
+    pub static ref ENV_PREFIX: String = PROJECT_NAME.to_uppercase();
+    /// The environment variable overriding the configuration directory.
+    pub static ref CONFIG_DIR_ENV: String = format!("{}_CONFIG", *ENV_PREFIX);
+}
+
+/// The application configuration, merged from the following sources in the order of increasing
+/// precedence:
+/// 1. The built-in defaults in `.config/config.json5`;
+/// 2. The `config.*` files in the configuration directory;
+/// 3. The settings saved into `SETTINGS_FILE` in the configuration directory;
+/// 4. The file passed with `--config`;
+/// 5. Environment variables prefixed with `ENV_PREFIX`;
+/// 6. Command-line arguments.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Config {
+    /// Number of ticks per second.
+    pub tick_rate: f64,
+    /// Number of frames per second.
+    pub frame_rate: f64,
+    /// The path to a registry directory containing a `registry.cbor` file.
+    pub registry_directory: PathBuf,
+    /// The number of seconds within which the next key of a key sequence must be pressed.
+    pub key_sequence_timeout: f64,
+    #[serde(default)]
+    pub keybindings: KeyBindings,
+    pub ui: UiConfig,
+    pub search: SearchConfig,
+    /// The keys set by `--config`, environment variables or command-line arguments, which take
+    /// precedence over the file written by `save`.
+    #[serde(skip)]
+    pub overridden_keys: BTreeSet<&'static str>,
+}
+
+/// The outcome of `Config::save`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedConfig {
+    pub path: PathBuf,
+    /// The saved keys that do not take effect on the next start, as they are set by a source
+    /// with a higher precedence.
+    pub overridden_keys: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UiConfig {
+    /// The number of seconds after which text copied to the clipboard is overwritten.
+    /// Zero disables clearing the clipboard. Best effort, see `Args::clipboard_clear_timeout`.
+    pub clipboard_clear_timeout: f64,
+    /// The initial format of the record content.
+    pub content_view_mode: ContentViewMode,
+    /// Whether the record names in the breadcrumb are initially revealed.
+    pub reveal_breadcrumb: bool,
+    /// Whether mouse input is enabled. Disabling it allows selecting text in the terminal.
+    pub mouse: bool,
+    /// The maximum width of the user interface.
+    #[serde(default)]
+    pub max_width: Option<u16>,
+    /// The maximum height of the user interface.
+    #[serde(default)]
+    pub max_height: Option<u16>,
+}
+
+/// Limits of searching for records in the registry. Records beyond the limits are not found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct SearchConfig {
+    /// The number of consecutive missing versions to look past when listing the versions of a
+    /// record.
+    pub max_version_lookahead: u64,
+    /// The number of attempts to resolve colliding fragment file names.
+    pub max_collision_resolution_attempts: u64,
+}
+
+impl Config {
+    /// Loads the configuration from all sources, failing on any missing or invalid value.
+    pub fn new(args: &Args) -> Result<Self> {
+        let config_dir = get_config_dir();
+        let mut builder =
+            config::Config::builder().add_source(File::from_str(DEFAULT_CONFIG, FileFormat::Json5));
+
+        for (file_name, file_format) in CONFIG_FILES {
+            builder = builder.add_source(
+                File::from(config_dir.join(file_name))
+                    .format(file_format)
+                    .required(false),
+            );
+        }
+
+        builder = builder.add_source(
+            File::from(config_dir.join(SETTINGS_FILE))
+                .format(FileFormat::Json)
+                .required(false),
+        );
+
+        if let Some(config_path) = &args.config {
+            builder = builder.add_source(File::from(config_path.as_path()).required(true));
+        }
+
+        let mut config: Self = builder
+            .add_source(get_environment_source())
+            .build()
+            .and_then(config::Config::try_deserialize)
+            .wrap_err("Failed to load the configuration")?;
+
+        config.find_overridden_keys(args)?;
+        config.apply_args(args);
+        config.validate().wrap_err("Invalid configuration")?;
+
+        Ok(config)
+    }
+
+    /// Records the editable keys set by `--config` or environment variables.
+    fn find_overridden_keys(&mut self, args: &Args) -> Result<()> {
+        let mut builder = config::Config::builder();
+
+        if let Some(config_path) = &args.config {
+            builder = builder.add_source(File::from(config_path.as_path()).required(true));
+        }
+
+        let overrides = builder
+            .add_source(get_environment_source())
+            .build()
+            .wrap_err("Failed to load the configuration")?;
+
+        for (key, _) in self.get_editable_values()? {
+            if overrides.get::<config::Value>(key).is_ok() {
+                self.overridden_keys.insert(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_args(&mut self, args: &Args) {
+        if let Some(tick_rate) = args.tick_rate {
+            self.tick_rate = tick_rate;
+            self.overridden_keys.insert("tick_rate");
+        }
+
+        if let Some(frame_rate) = args.frame_rate {
+            self.frame_rate = frame_rate;
+            self.overridden_keys.insert("frame_rate");
+        }
+
+        if let Some(registry_directory) = &args.registry_directory {
+            self.registry_directory = registry_directory.clone();
+            self.overridden_keys.insert("registry_directory");
+        }
+
+        if let Some(clipboard_clear_timeout) = args.clipboard_clear_timeout {
+            self.ui.clipboard_clear_timeout = clipboard_clear_timeout;
+            self.overridden_keys.insert("ui.clipboard_clear_timeout");
+        }
+
+        if let Some(max_width) = args.force_max_width {
+            self.ui.max_width = Some(max_width);
+            self.overridden_keys.insert("ui.max_width");
+        }
+
+        if let Some(max_height) = args.force_max_height {
+            self.ui.max_height = Some(max_height);
+            self.overridden_keys.insert("ui.max_height");
+        }
+
+        if let Some(max_version_lookahead) = args.max_version_lookahead {
+            self.search.max_version_lookahead = max_version_lookahead;
+            self.overridden_keys.insert("search.max_version_lookahead");
+        }
+
+        if let Some(max_collision_resolution_attempts) = args.max_collision_resolution_attempts {
+            self.search.max_collision_resolution_attempts = max_collision_resolution_attempts;
+            self.overridden_keys
+                .insert("search.max_collision_resolution_attempts");
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for (key, value) in [
+            ("tick_rate", self.tick_rate),
+            ("frame_rate", self.frame_rate),
+            ("key_sequence_timeout", self.key_sequence_timeout),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(eyre!("`{key}` must be a positive number, got {value}"));
+            }
+        }
+
+        let clipboard_clear_timeout = self.ui.clipboard_clear_timeout;
+
+        if !(clipboard_clear_timeout.is_finite() && clipboard_clear_timeout >= 0.0) {
+            return Err(eyre!(
+                "`ui.clipboard_clear_timeout` must be a non-negative number, got {clipboard_clear_timeout}"
+            ));
+        }
+
+        if self.search.max_collision_resolution_attempts == 0 {
+            return Err(eyre!(
+                "`search.max_collision_resolution_attempts` must be at least 1"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// The values editable in the settings, by their dot-separated keys. Unset values are null.
+    fn get_editable_values(&self) -> Result<Vec<(&'static str, serde_json::Value)>> {
+        Ok(vec![
+            ("tick_rate", self.tick_rate.into()),
+            ("frame_rate", self.frame_rate.into()),
+            (
+                "ui.clipboard_clear_timeout",
+                self.ui.clipboard_clear_timeout.into(),
+            ),
+            (
+                "ui.content_view_mode",
+                serde_json::to_value(self.ui.content_view_mode)?,
+            ),
+            ("ui.reveal_breadcrumb", self.ui.reveal_breadcrumb.into()),
+            ("ui.mouse", self.ui.mouse.into()),
+            ("ui.max_width", self.ui.max_width.into()),
+            ("ui.max_height", self.ui.max_height.into()),
+        ])
+    }
+
+    /// Writes the editable values of `edited` that differ from those of `self` into
+    /// `SETTINGS_FILE`, returning `None` if there are no differences. Values that were not edited
+    /// are left to whichever source set them, and unset values are removed from the file.
+    pub fn save(&self, edited: &Config) -> Result<Option<SavedConfig>> {
+        let changes = self
+            .get_editable_values()?
+            .into_iter()
+            .zip(edited.get_editable_values()?)
+            .filter(|((_, value), (_, edited_value))| value != edited_value)
+            .map(|(_, change)| change)
+            .collect::<Vec<_>>();
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        let config_dir = get_config_dir();
+        let path = config_dir.join(SETTINGS_FILE);
+        let mut document = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str::<serde_json::Value>(&content)
+                .wrap_err_with(|| format!("Failed to parse {}", path.display()))?,
+            Err(error) if error.kind() == ErrorKind::NotFound => serde_json::json!({}),
+            Err(error) => {
+                return Err(error).wrap_err_with(|| format!("Failed to read {}", path.display()));
+            }
+        };
+
+        for (key, value) in &changes {
+            let not_an_object = || {
+                eyre!(
+                    "The parent of `{key}` in {} is not an object",
+                    path.display()
+                )
+            };
+            let (parents, name) = key.rsplit_once('.').unwrap_or(("", key));
+            let mut entries = document.as_object_mut().ok_or_else(not_an_object)?;
+
+            for parent in parents.split('.').filter(|parent| !parent.is_empty()) {
+                entries = entries
+                    .entry(parent)
+                    .or_insert_with(|| serde_json::json!({}))
+                    .as_object_mut()
+                    .ok_or_else(not_an_object)?;
+            }
+
+            match value {
+                serde_json::Value::Null => entries.remove(name),
+                value => entries.insert(name.into(), value.clone()),
+            };
+        }
+
+        std::fs::create_dir_all(&config_dir)
+            .wrap_err_with(|| format!("Failed to create {}", config_dir.display()))?;
+        std::fs::write(&path, serde_json::to_string_pretty(&document)? + "\n")
+            .wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+
+        let overridden_keys = changes
+            .iter()
+            .map(|(key, _)| *key)
+            .filter(|key| self.overridden_keys.contains(key))
+            .collect();
+
+        Ok(Some(SavedConfig {
+            path,
+            overridden_keys,
+        }))
+    }
+}
+
+/// The source of the configuration keys overridden by environment variables.
+fn get_environment_source() -> Environment {
+    Environment::with_prefix(&ENV_PREFIX)
+        .prefix_separator("_")
+        .separator("__")
+}
+
+/// The directory containing the user configuration files.
+pub fn get_config_dir() -> PathBuf {
+    if let Some(config_dir) = std::env::var_os(&*CONFIG_DIR_ENV) {
+        config_dir.into()
+    } else if let Some(project_dirs) = ProjectDirs::from("", "", &PKG_NAME) {
+        project_dirs.config_dir().to_path_buf()
+    } else {
+        PathBuf::from(".config")
+    }
+}
@@ -0,0 +1,182 @@
+use itertools::{EitherOrBoth, Itertools};
+use similar::{Algorithm, DiffTag, capture_diff_slices};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp<'a> {
+    Equal(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Computes a line diff of `old` and `new` using Myers' algorithm, which needs space linear in
+/// the number of lines.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffOp<'a>> {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+
+    for op in capture_diff_slices(Algorithm::Myers, &old, &new) {
+        let (tag, old_range, new_range) = op.as_tag_tuple();
+
+        match tag {
+            DiffTag::Equal => ops.extend(old[old_range].iter().map(|line| DiffOp::Equal(line))),
+            DiffTag::Delete => ops.extend(old[old_range].iter().map(|line| DiffOp::Removed(line))),
+            DiffTag::Insert => ops.extend(new[new_range].iter().map(|line| DiffOp::Added(line))),
+            DiffTag::Replace => {
+                ops.extend(old[old_range].iter().map(|line| DiffOp::Removed(line)));
+                ops.extend(new[new_range].iter().map(|line| DiffOp::Added(line)));
+            }
+        }
+    }
+
+    ops
+}
+
+/// Arranges diff operations into rows of a side-by-side view, pairing removed lines with the
+/// lines added in their place.
+pub fn side_by_side<'a>(ops: &[DiffOp<'a>]) -> Vec<EitherOrBoth<DiffOp<'a>>> {
+    fn flush<'a>(
+        rows: &mut Vec<EitherOrBoth<DiffOp<'a>>>,
+        removed: &mut Vec<DiffOp<'a>>,
+        added: &mut Vec<DiffOp<'a>>,
+    ) {
+        rows.extend(removed.drain(..).zip_longest(added.drain(..)));
+    }
+
+    let mut rows = Vec::with_capacity(ops.len());
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    for op in ops {
+        match op {
+            DiffOp::Removed(_) => removed.push(*op),
+            DiffOp::Added(_) => added.push(*op),
+            DiffOp::Equal(_) => {
+                flush(&mut rows, &mut removed, &mut added);
+                rows.push(EitherOrBoth::Both(*op, *op));
+            }
+        }
+    }
+
+    flush(&mut rows, &mut removed, &mut added);
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn diff_lines_equal() {
+        assert_eq!(
+            diff_lines("a\nb", "a\nb"),
+            vec![DiffOp::Equal("a"), DiffOp::Equal("b")]
+        );
+    }
+
+    #[test]
+    fn diff_lines_empty() {
+        assert_eq!(diff_lines("", ""), vec![]);
+        assert_eq!(
+            diff_lines("", "a\nb"),
+            vec![DiffOp::Added("a"), DiffOp::Added("b")]
+        );
+        assert_eq!(
+            diff_lines("a\nb", ""),
+            vec![DiffOp::Removed("a"), DiffOp::Removed("b")]
+        );
+    }
+
+    #[test]
+    fn diff_lines_keeps_common_lines() {
+        assert_eq!(
+            diff_lines("a\nb\nc\nd", "a\nc\nx\nd"),
+            vec![
+                DiffOp::Equal("a"),
+                DiffOp::Removed("b"),
+                DiffOp::Equal("c"),
+                DiffOp::Added("x"),
+                DiffOp::Equal("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_lines_replaced_line() {
+        assert_eq!(
+            diff_lines("a\nb\nc", "a\nx\nc"),
+            vec![
+                DiffOp::Equal("a"),
+                DiffOp::Removed("b"),
+                DiffOp::Added("x"),
+                DiffOp::Equal("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_lines_ignores_trailing_newline() {
+        assert_eq!(diff_lines("a\n", "a"), vec![DiffOp::Equal("a")]);
+    }
+
+    #[test]
+    fn diff_lines_large_input() {
+        let old = (0..20_000).map(|i| i.to_string()).join("\n");
+        let new = (0..20_000)
+            .map(|i| {
+                if i % 1000 == 0 {
+                    "x".into()
+                } else {
+                    i.to_string()
+                }
+            })
+            .join("\n");
+        let ops = diff_lines(&old, &new);
+
+        assert_eq!(
+            ops.iter()
+                .filter(|op| matches!(op, DiffOp::Removed(_)))
+                .count(),
+            20
+        );
+        assert_eq!(
+            ops.iter()
+                .filter(|op| matches!(op, DiffOp::Added(_)))
+                .count(),
+            20
+        );
+        assert_eq!(ops.len(), 20_020);
+    }
+
+    #[test]
+    fn side_by_side_pairs_removed_with_added() {
+        let rows = side_by_side(&diff_lines("a\nb\nc\nd", "a\nx\ny\nz\nd"));
+
+        assert_eq!(
+            rows,
+            vec![
+                EitherOrBoth::Both(DiffOp::Equal("a"), DiffOp::Equal("a")),
+                EitherOrBoth::Both(DiffOp::Removed("b"), DiffOp::Added("x")),
+                EitherOrBoth::Both(DiffOp::Removed("c"), DiffOp::Added("y")),
+                EitherOrBoth::Right(DiffOp::Added("z")),
+                EitherOrBoth::Both(DiffOp::Equal("d"), DiffOp::Equal("d")),
+            ]
+        );
+    }
+
+    #[test]
+    fn side_by_side_trailing_removals() {
+        let rows = side_by_side(&diff_lines("a\nb\nc", "a"));
+
+        assert_eq!(
+            rows,
+            vec![
+                EitherOrBoth::Both(DiffOp::Equal("a"), DiffOp::Equal("a")),
+                EitherOrBoth::Left(DiffOp::Removed("b")),
+                EitherOrBoth::Left(DiffOp::Removed("c")),
+            ]
+        );
+    }
+}
@@ -80,12 +80,31 @@ macro_rules! trace_dbg {
 }
 
 pub async fn report(action_tx: &UnboundedSender<Action>, f: impl async FnOnce() -> Result<()>) {
+    report_with_open_generation(action_tx, None, f).await;
+}
+
+/// Like `report`, but tags the error with the generation of the request to open a record that
+/// the task serves, so that only that request is ended by it.
+pub async fn report_open_request(
+    action_tx: &UnboundedSender<Action>,
+    generation: u64,
+    f: impl async FnOnce() -> Result<()>,
+) {
+    report_with_open_generation(action_tx, Some(generation), f).await;
+}
+
+async fn report_with_open_generation(
+    action_tx: &UnboundedSender<Action>,
+    open_generation: Option<u64>,
+    f: impl async FnOnce() -> Result<()>,
+) {
     if let Err(error) = (f)().await {
         let message = error.to_string();
 
         action_tx
             .send(Action::BroadcastMessage(ComponentMessage::ShowError {
                 error: message,
+                open_generation,
             }))
             .unwrap();
     }
@@ -0,0 +1,64 @@
+use ratatui::text::{Line, Span};
+
+use crate::color::{ColorOklch, TextColor};
+
+/// The number of bytes per line of a hex dump, fitting within `width` columns.
+fn bytes_per_line(width: u16) -> usize {
+    [16, 8, 4]
+        .into_iter()
+        .find(|&bytes| line_width(bytes) <= width as usize)
+        .unwrap_or(4)
+}
+
+/// The width of a hex dump line with the given number of bytes, groups of 8 bytes are separated
+/// by an extra space.
+fn line_width(bytes: usize) -> usize {
+    8 + 2 + 3 * bytes - 1 + (bytes - 1) / 8 + 3 + bytes + 1
+}
+
+/// Formats the data as a classic hex dump, with offsets, hexadecimal bytes and ASCII characters.
+pub fn hex_dump_lines(data: &[u8], width: u16) -> Vec<Line<'static>> {
+    let bytes_per_line = bytes_per_line(width);
+    let dim = TextColor::default().fg(ColorOklch::new(0.5, 0.0, 0.0));
+
+    data.chunks(bytes_per_line)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut hex = String::with_capacity(3 * bytes_per_line);
+
+            for position in 0..bytes_per_line {
+                if position > 0 {
+                    hex.push(' ');
+                }
+
+                if position > 0 && position % 8 == 0 {
+                    hex.push(' ');
+                }
+
+                match chunk.get(position) {
+                    Some(byte) => hex.push_str(&format!("{byte:02x}")),
+                    None => hex.push_str("  "),
+                }
+            }
+
+            let ascii = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+
+            Line::from_iter([
+                Span::styled(format!("{:08x}", index * bytes_per_line), &dim),
+                Span::raw(format!("  {hex}  ")),
+                Span::styled("|", &dim),
+                Span::raw(ascii),
+                Span::styled("|", &dim),
+            ])
+        })
+        .collect()
+}
@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use derive_deref::{Deref, DerefMut};
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, de};
+use strum::Display;
+
+use crate::action::Action;
+
+/// Determines which set of keybindings is active.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Display, Deserialize)]
+pub enum Mode {
+    #[default]
+    Home,
+}
+
+/// A sequence of keys pressed one after another, such as `<g><g>`.
+pub type KeySequence = Vec<KeyEvent>;
+
+/// Maps key sequences to the actions they trigger, separately for each mode.
+#[derive(Debug, Clone, Default, PartialEq, Deref, DerefMut)]
+pub struct KeyBindings(pub HashMap<Mode, HashMap<KeySequence, Action>>);
+
+impl KeyBindings {
+    /// The action bound to `sequence` in `mode`, if any.
+    pub fn get_action(&self, mode: Mode, sequence: &[KeyEvent]) -> Option<&Action> {
+        self.get(&mode)?.get(sequence)
+    }
+
+    /// Returns `true` iff `sequence` may be completed to a sequence bound in `mode` by pressing
+    /// more keys.
+    pub fn is_prefix(&self, mode: Mode, sequence: &[KeyEvent]) -> bool {
+        self.get(&mode).is_some_and(|bindings| {
+            bindings
+                .keys()
+                .any(|bound| bound.len() > sequence.len() && bound.starts_with(sequence))
+        })
+    }
+
+    /// Fails if two equal sequences in `bindings` are bound to different actions, or if one
+    /// sequence is a prefix of another, as the longer one could then never be triggered.
+    fn check_conflicts(
+        mode: Mode,
+        bindings: &[(String, KeySequence, Action)],
+    ) -> Result<(), String> {
+        for ((raw_a, sequence_a, action_a), (raw_b, sequence_b, action_b)) in
+            bindings.iter().tuple_combinations()
+        {
+            if sequence_a == sequence_b {
+                if action_a != action_b {
+                    return Err(format!(
+                        "Conflicting keybindings in mode {mode}: `{raw_a}` ({action_a}) and `{raw_b}` ({action_b}) refer to the same keys"
+                    ));
+                }
+            } else if sequence_b.starts_with(sequence_a) || sequence_a.starts_with(sequence_b) {
+                let (raw_short, raw_long) = if sequence_a.len() < sequence_b.len() {
+                    (raw_a, raw_b)
+                } else {
+                    (raw_b, raw_a)
+                };
+
+                return Err(format!(
+                    "Conflicting keybindings in mode {mode}: `{raw_short}` prevents `{raw_long}` from ever being completed"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parsed = HashMap::<Mode, HashMap<String, Action>>::deserialize(deserializer)?;
+        let mut keybindings = HashMap::new();
+
+        for (mode, bindings) in parsed {
+            let bindings = bindings
+                .into_iter()
+                .sorted_by(|(raw_a, _), (raw_b, _)| raw_a.cmp(raw_b))
+                .map(|(raw, action)| {
+                    let sequence = parse_key_sequence(&raw)
+                        .map_err(|error| de::Error::custom(format!("In mode {mode}: {error}")))?;
+                    Ok((raw, sequence, action))
+                })
+                .collect::<Result<Vec<_>, D::Error>>()?;
+
+            KeyBindings::check_conflicts(mode, &bindings).map_err(de::Error::custom)?;
+            keybindings.insert(
+                mode,
+                bindings
+                    .into_iter()
+                    .map(|(_, sequence, action)| (sequence, action))
+                    .collect(),
+            );
+        }
+
+        Ok(Self(keybindings))
+    }
+}
+
+/// Brings a key press into a canonical form, so that equivalent key presses compare equal
+/// regardless of how they are written or reported by the terminal.
+pub fn normalize_key_event(code: KeyCode, mut modifiers: KeyModifiers) -> KeyEvent {
+    let code = match code {
+        // The case of a character already tells whether shift was held.
+        KeyCode::Char(c) => {
+            let c = if modifiers.contains(KeyModifiers::SHIFT) {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            };
+
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::Char(c)
+        }
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        KeyCode::BackTab => {
+            modifiers.insert(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        code => code,
+    };
+
+    KeyEvent::new(code, modifiers)
+}
+
+/// Parses a sequence of keys written as `<key>`, one after another, e.g. `<g><g>`.
+pub fn parse_key_sequence(raw: &str) -> Result<KeySequence, String> {
+    let mut sequence = Vec::new();
+    let mut rest = raw;
+
+    while !rest.is_empty() {
+        let inner = rest
+            .strip_prefix('<')
+            .ok_or_else(|| format!("Expected `<` in the key sequence `{raw}`"))?;
+        // Skip the first character, so that `>` itself can be bound as `<>>`.
+        let end = inner
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == '>')
+            .map(|(index, _)| index)
+            .ok_or_else(|| format!("Unterminated key in the key sequence `{raw}`"))?;
+
+        sequence.push(parse_key_event(&inner[..end])?);
+        rest = &inner[end + 1..];
+    }
+
+    if sequence.is_empty() {
+        return Err("Empty key sequence".into());
+    }
+
+    Ok(sequence)
+}
+
+/// Parses a single key with optional modifiers, such as `Ctrl-d`, `Alt-Left` or `G`.
+/// Modifiers and key names are case-insensitive, single characters are not.
+pub fn parse_key_event(raw: &str) -> Result<KeyEvent, String> {
+    const MODIFIER_PREFIXES: [(&str, KeyModifiers); 4] = [
+        ("ctrl-", KeyModifiers::CONTROL),
+        ("control-", KeyModifiers::CONTROL),
+        ("alt-", KeyModifiers::ALT),
+        ("shift-", KeyModifiers::SHIFT),
+    ];
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = raw;
+
+    // A trailing `-` is a key rather than a separator, as in `Ctrl--`.
+    while let Some((prefix, modifier)) = MODIFIER_PREFIXES.iter().find(|(prefix, _)| {
+        rest.len() > prefix.len()
+            && rest
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    }) {
+        modifiers.insert(*modifier);
+        rest = &rest[prefix.len()..];
+    }
+
+    let code = match rest.to_ascii_lowercase().as_str() {
+        "esc" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "space" => KeyCode::Char(' '),
+        lowercase => {
+            let mut chars = rest.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => lowercase
+                    .strip_prefix('f')
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .filter(|number| (1..=24).contains(number))
+                    .map(KeyCode::F)
+                    .ok_or_else(|| format!("Unknown key `{raw}`"))?,
+            }
+        }
+    };
+
+    Ok(normalize_key_event(code, modifiers))
+}
+
+/// Formats a sequence of keys the way it is written in the configuration, e.g. `<g><g>`.
+pub fn format_key_sequence(sequence: &[KeyEvent]) -> String {
+    sequence
+        .iter()
+        .map(|key_event| format!("<{}>", format_key_event(key_event)))
+        .collect()
+}
+
+/// Formats a single key with its modifiers, such that `parse_key_event` parses it back.
+pub fn format_key_event(key_event: &KeyEvent) -> String {
+    let mut formatted = String::new();
+
+    if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        formatted.push_str("Ctrl-");
+    }
+
+    if key_event.modifiers.contains(KeyModifiers::ALT) {
+        formatted.push_str("Alt-");
+    }
+
+    // Shift is implied by the back tab key.
+    if key_event.modifiers.contains(KeyModifiers::SHIFT) && key_event.code != KeyCode::BackTab {
+        formatted.push_str("Shift-");
+    }
+
+    match key_event.code {
+        KeyCode::Char(' ') => formatted.push_str("Space"),
+        KeyCode::Char(c) => formatted.push(c),
+        KeyCode::F(number) => formatted.push_str(&format!("F{number}")),
+        KeyCode::Esc => formatted.push_str("Esc"),
+        KeyCode::Enter => formatted.push_str("Enter"),
+        KeyCode::Left => formatted.push_str("Left"),
+        KeyCode::Right => formatted.push_str("Right"),
+        KeyCode::Up => formatted.push_str("Up"),
+        KeyCode::Down => formatted.push_str("Down"),
+        KeyCode::Home => formatted.push_str("Home"),
+        KeyCode::End => formatted.push_str("End"),
+        KeyCode::PageUp => formatted.push_str("PageUp"),
+        KeyCode::PageDown => formatted.push_str("PageDown"),
+        KeyCode::Tab => formatted.push_str("Tab"),
+        KeyCode::BackTab => formatted.push_str("BackTab"),
+        KeyCode::Backspace => formatted.push_str("Backspace"),
+        KeyCode::Delete => formatted.push_str("Delete"),
+        KeyCode::Insert => formatted.push_str("Insert"),
+        code => formatted.push_str(&code.to_string()),
+    }
+
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn parse_key_sequence_greater_than() {
+        assert_eq!(
+            parse_key_sequence("<>>"),
+            Ok(vec![key(KeyCode::Char('>'), KeyModifiers::NONE)]),
+        );
+        assert_eq!(
+            parse_key_sequence("<g><>>"),
+            Ok(vec![
+                key(KeyCode::Char('g'), KeyModifiers::NONE),
+                key(KeyCode::Char('>'), KeyModifiers::NONE),
+            ]),
+        );
+    }
+
+    #[test]
+    fn parse_key_sequence_invalid() {
+        assert!(parse_key_sequence("").is_err());
+        assert!(parse_key_sequence("g").is_err());
+        assert!(parse_key_sequence("<g").is_err());
+        assert!(parse_key_sequence("<unknown>").is_err());
+    }
+
+    #[test]
+    fn parse_key_event_trailing_dash() {
+        assert_eq!(
+            parse_key_event("Ctrl--"),
+            Ok(key(KeyCode::Char('-'), KeyModifiers::CONTROL)),
+        );
+        assert_eq!(
+            parse_key_event("-"),
+            Ok(key(KeyCode::Char('-'), KeyModifiers::NONE)),
+        );
+    }
+
+    #[test]
+    fn parse_key_event_case_insensitive_modifiers() {
+        assert_eq!(
+            parse_key_event("cTrL-ALT-pageup"),
+            Ok(key(
+                KeyCode::PageUp,
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            )),
+        );
+        assert_eq!(
+            parse_key_event("F12"),
+            Ok(key(KeyCode::F(12), KeyModifiers::NONE))
+        );
+        assert!(parse_key_event("F25").is_err());
+    }
+
+    #[test]
+    fn normalize_shift_char() {
+        let expected = key(KeyCode::Char('G'), KeyModifiers::NONE);
+
+        assert_eq!(parse_key_event("G"), Ok(expected));
+        assert_eq!(parse_key_event("Shift-g"), Ok(expected));
+        assert_eq!(
+            normalize_key_event(KeyCode::Char('G'), KeyModifiers::SHIFT),
+            expected
+        );
+        assert_eq!(
+            normalize_key_event(KeyCode::Char('g'), KeyModifiers::SHIFT),
+            expected
+        );
+    }
+
+    #[test]
+    fn normalize_back_tab() {
+        let expected = key(KeyCode::BackTab, KeyModifiers::SHIFT);
+
+        assert_eq!(parse_key_event("BackTab"), Ok(expected));
+        assert_eq!(parse_key_event("Shift-Tab"), Ok(expected));
+        assert_eq!(
+            normalize_key_event(KeyCode::BackTab, KeyModifiers::NONE),
+            expected
+        );
+        assert_eq!(
+            normalize_key_event(KeyCode::Tab, KeyModifiers::SHIFT),
+            expected
+        );
+    }
+
+    #[test]
+    fn format_key_event_round_trip() {
+        let key_events = [
+            key(KeyCode::Char('>'), KeyModifiers::NONE),
+            key(KeyCode::Char('-'), KeyModifiers::CONTROL),
+            key(KeyCode::Char('G'), KeyModifiers::NONE),
+            key(KeyCode::Char(' '), KeyModifiers::ALT),
+            key(KeyCode::BackTab, KeyModifiers::SHIFT),
+            key(KeyCode::Left, KeyModifiers::SHIFT | KeyModifiers::CONTROL),
+            key(KeyCode::F(5), KeyModifiers::NONE),
+            key(KeyCode::Delete, KeyModifiers::NONE),
+        ];
+
+        for key_event in key_events {
+            let formatted = format_key_event(&key_event);
+
+            assert_eq!(parse_key_event(&formatted), Ok(key_event), "{formatted}");
+        }
+
+        assert_eq!(format_key_event(&key_events[4]), "BackTab");
+        assert_eq!(
+            format_key_sequence(&key_events[..2]),
+            "<>><Ctrl-->".to_string()
+        );
+    }
+
+    #[test]
+    fn deserialize_prefix_conflict() {
+        let error =
+            json5::from_str::<KeyBindings>(r#"{ "Home": { "<g>": "Quit", "<g><g>": "Suspend" } }"#)
+                .unwrap_err()
+                .to_string();
+
+        assert!(error.contains("`<g>` prevents `<g><g>`"), "{error}");
+    }
+
+    #[test]
+    fn deserialize_equal_sequences() {
+        // Equal sequences written differently may be bound to the same action, but not to
+        // different ones.
+        let keybindings =
+            json5::from_str::<KeyBindings>(r#"{ "Home": { "<G>": "Quit", "<Shift-g>": "Quit" } }"#)
+                .unwrap();
+
+        assert_eq!(
+            keybindings.get_action(Mode::Home, &[key(KeyCode::Char('G'), KeyModifiers::NONE)]),
+            Some(&Action::Quit),
+        );
+        assert!(
+            json5::from_str::<KeyBindings>(
+                r#"{ "Home": { "<G>": "Quit", "<Shift-g>": "Suspend" } }"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn is_prefix() {
+        let keybindings =
+            json5::from_str::<KeyBindings>(r#"{ "Home": { "<g><g>": "Quit" } }"#).unwrap();
+        let g = key(KeyCode::Char('g'), KeyModifiers::NONE);
+
+        assert!(keybindings.is_prefix(Mode::Home, &[g]));
+        assert!(!keybindings.is_prefix(Mode::Home, &[g, g]));
+        assert_eq!(
+            keybindings.get_action(Mode::Home, &[g, g]),
+            Some(&Action::Quit)
+        );
+    }
+}
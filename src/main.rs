@@ -10,6 +10,7 @@ use color_eyre::Result;
 use tracing::{debug, Instrument};
 
 use crate::app::App;
+use crate::config::Config;
 
 mod action;
 mod app;
@@ -18,10 +19,15 @@ mod cbor;
 mod color;
 mod component;
 mod components;
+mod config;
+mod diff;
 mod env;
 mod error;
+mod hex;
+mod keybindings;
 mod logging;
 mod rect;
+mod registry;
 mod tui;
 
 #[tokio::main]
@@ -31,7 +37,8 @@ async fn main() -> Result<()> {
 
     async move {
         let args = Arc::new(Args::parse());
-        let mut app = App::new(&args).await?;
+        let config = Arc::new(Config::new(&args)?);
+        let mut app = App::new(&args, &config).await?;
         app.run().await?;
         Ok(()) as Result<()>
     }
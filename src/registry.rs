@@ -0,0 +1,301 @@
+use std::collections::{BTreeSet, HashSet};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use rrr::crypto::encryption::EncryptionAlgorithm;
+use rrr::record::{HashedRecordKey, Record, RecordReadVersionSuccess, RecordVersion};
+use rrr::registry::{Registry, RegistryConfig};
+use rrr::utils::fd_lock::{ReadLock, WriteLock};
+
+/// The encryption algorithm used for record versions written by this application.
+const ENCRYPTION_ALGORITHM: EncryptionAlgorithm = EncryptionAlgorithm::Aes256Gcm;
+
+/// How many times larger a search limit gets when probing whether it ended a listing.
+const SEARCH_LIMIT_PROBE_FACTOR: u64 = 2;
+
+/// A search limit that ended the listing of the versions of a record short of an existing
+/// version, which a larger limit would reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchLimit {
+    /// The version lies past more missing versions than the version lookahead allows.
+    VersionLookahead(RecordVersion),
+    /// The fragments of the version collide more often than collision resolution attempts are
+    /// allowed.
+    CollisionResolutionAttempts(RecordVersion),
+}
+
+/// The fragment files ("shards") of a registry, and which of them were accessed during this
+/// session.
+#[derive(Debug, Default)]
+pub struct RegistryShards {
+    /// All fragment files in the records directory, sorted by path.
+    pub available: BTreeSet<PathBuf>,
+    /// The fragment files of the record versions listed during this session.
+    pub visited: HashSet<PathBuf>,
+}
+
+impl RegistryShards {
+    /// The ratio of visited to available shards in the range [0; 1].
+    pub fn visited_ratio(&self) -> f32 {
+        if self.available.is_empty() {
+            0.0
+        } else {
+            self.visited.len() as f32 / self.available.len() as f32
+        }
+    }
+}
+
+#[derive(Debug)]
+enum LockedRegistry {
+    ReadOnly(Registry<ReadLock>),
+    ReadWrite(Registry<WriteLock>),
+}
+
+/// A registry opened either for reading only, or for both reading and writing.
+#[derive(Debug)]
+pub struct AnyRegistry {
+    registry: LockedRegistry,
+    /// Shared, so that the shards can be displayed without waiting for the registry lock.
+    shards: Arc<Mutex<RegistryShards>>,
+}
+
+impl AnyRegistry {
+    /// Opens the registry, taking the write lock if `writable` is `true`.
+    pub async fn open(directory_path: PathBuf, writable: bool) -> Result<Self> {
+        let registry = if writable {
+            LockedRegistry::ReadWrite(Registry::open(directory_path).await?)
+        } else {
+            LockedRegistry::ReadOnly(Registry::open(directory_path).await?)
+        };
+        let result = Self {
+            registry,
+            shards: Default::default(),
+        };
+
+        result.scan_shards().await?;
+
+        Ok(result)
+    }
+
+    pub fn is_writable(&self) -> bool {
+        matches!(self.registry, LockedRegistry::ReadWrite(_))
+    }
+
+    pub fn config(&self) -> &RegistryConfig {
+        match &self.registry {
+            LockedRegistry::ReadOnly(registry) => &registry.config,
+            LockedRegistry::ReadWrite(registry) => &registry.config,
+        }
+    }
+
+    pub fn shards(&self) -> &Arc<Mutex<RegistryShards>> {
+        &self.shards
+    }
+
+    fn get_records_directory(&self) -> PathBuf {
+        match &self.registry {
+            LockedRegistry::ReadOnly(registry) => registry.get_records_directory(),
+            LockedRegistry::ReadWrite(registry) => registry.get_records_directory(),
+        }
+    }
+
+    /// Updates the available shards from the fragment files in the records directory.
+    async fn scan_shards(&self) -> Result<()> {
+        let available = list_files_recursively(&self.get_records_directory())
+            .await
+            .wrap_err("Failed to list the fragment files of the registry")?;
+
+        self.shards.lock().unwrap().available = available;
+
+        Ok(())
+    }
+
+    /// Lists the versions of the record, in ascending order.
+    pub async fn list_record_versions(
+        &self,
+        hashed_record_key: &HashedRecordKey,
+        max_version_lookahead: u64,
+        max_collision_resolution_attempts: u64,
+    ) -> Result<Vec<RecordVersion>> {
+        let versions = match &self.registry {
+            LockedRegistry::ReadOnly(registry) => {
+                registry
+                    .list_record_versions(
+                        hashed_record_key,
+                        max_version_lookahead,
+                        max_collision_resolution_attempts,
+                    )
+                    .await?
+            }
+            LockedRegistry::ReadWrite(registry) => {
+                registry
+                    .list_record_versions(
+                        hashed_record_key,
+                        max_version_lookahead,
+                        max_collision_resolution_attempts,
+                    )
+                    .await?
+            }
+        };
+
+        let mut shards = self.shards.lock().unwrap();
+
+        for fragment_file_path in versions
+            .iter()
+            .flat_map(|version| &version.fragment_file_paths)
+        {
+            // The loaded versions are read from the same fragment files.
+            shards.available.insert(fragment_file_path.clone());
+            shards.visited.insert(fragment_file_path.clone());
+        }
+
+        Ok(versions
+            .into_iter()
+            .map(|version| version.record_version)
+            .collect())
+    }
+
+    /// Lists the versions of the record again, with one search limit enlarged at a time, and
+    /// returns the limit that ended the listing of `versions`, if an enlarged one lists more.
+    /// The probing listings are tracked in the shards like any other.
+    pub async fn probe_search_limits(
+        &self,
+        hashed_record_key: &HashedRecordKey,
+        versions: &[RecordVersion],
+        max_version_lookahead: u64,
+        max_collision_resolution_attempts: u64,
+    ) -> Result<Option<SearchLimit>> {
+        if let Some(record_version) = self
+            .list_unlisted_version(
+                hashed_record_key,
+                versions,
+                max_version_lookahead,
+                max_collision_resolution_attempts.saturating_mul(SEARCH_LIMIT_PROBE_FACTOR),
+            )
+            .await?
+        {
+            return Ok(Some(SearchLimit::CollisionResolutionAttempts(
+                record_version,
+            )));
+        }
+
+        Ok(self
+            .list_unlisted_version(
+                hashed_record_key,
+                versions,
+                max_version_lookahead
+                    .saturating_mul(SEARCH_LIMIT_PROBE_FACTOR)
+                    .max(1),
+                max_collision_resolution_attempts,
+            )
+            .await?
+            .map(SearchLimit::VersionLookahead))
+    }
+
+    /// Lists the versions of the record and returns the first one missing from `versions`.
+    async fn list_unlisted_version(
+        &self,
+        hashed_record_key: &HashedRecordKey,
+        versions: &[RecordVersion],
+        max_version_lookahead: u64,
+        max_collision_resolution_attempts: u64,
+    ) -> Result<Option<RecordVersion>> {
+        Ok(self
+            .list_record_versions(
+                hashed_record_key,
+                max_version_lookahead,
+                max_collision_resolution_attempts,
+            )
+            .await?
+            .into_iter()
+            .find(|record_version| versions.binary_search(record_version).is_err()))
+    }
+
+    pub async fn load_record(
+        &self,
+        hashed_record_key: &HashedRecordKey,
+        record_version: RecordVersion,
+        max_collision_resolution_attempts: u64,
+    ) -> Result<Option<RecordReadVersionSuccess>> {
+        Ok(match &self.registry {
+            LockedRegistry::ReadOnly(registry) => {
+                registry
+                    .load_record(
+                        hashed_record_key,
+                        record_version,
+                        max_collision_resolution_attempts,
+                    )
+                    .await?
+            }
+            LockedRegistry::ReadWrite(registry) => {
+                registry
+                    .load_record(
+                        hashed_record_key,
+                        record_version,
+                        max_collision_resolution_attempts,
+                    )
+                    .await?
+            }
+        })
+    }
+
+    /// Writes `record` as the version `record_version` of the record.
+    /// Fails if the registry is opened read-only.
+    pub async fn save_record(
+        &mut self,
+        hashed_record_key: &HashedRecordKey,
+        record: &Record,
+        record_version: RecordVersion,
+        max_collision_resolution_attempts: u64,
+    ) -> Result<()> {
+        let LockedRegistry::ReadWrite(registry) = &mut self.registry else {
+            return Err(eyre!("The registry is opened read-only."));
+        };
+
+        registry
+            .save_record(
+                &[],
+                hashed_record_key,
+                record,
+                record_version,
+                max_collision_resolution_attempts,
+                &[],
+                Some(ENCRYPTION_ALGORITHM),
+                false,
+            )
+            .await?;
+
+        // Writing a version creates new fragment files.
+        self.scan_shards().await
+    }
+}
+
+/// Lists all files within `directory` and its subdirectories.
+/// A missing directory is treated as empty.
+async fn list_files_recursively(directory: &Path) -> Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    let mut directories = vec![directory.to_path_buf()];
+
+    while let Some(directory) = directories.pop() {
+        let mut entries = match tokio::fs::read_dir(&directory).await {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == ErrorKind::NotFound => continue,
+            Err(error) => {
+                return Err(error)
+                    .wrap_err_with(|| format!("Failed to read {}", directory.display()));
+            }
+        };
+
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_dir() {
+                directories.push(entry.path());
+            } else {
+                files.insert(entry.path());
+            }
+        }
+    }
+
+    Ok(files)
+}
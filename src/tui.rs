@@ -90,6 +90,21 @@ impl Tui {
         self
     }
 
+    /// Starts or stops capturing mouse events while the terminal is in use.
+    pub fn set_mouse(&mut self, mouse: bool) -> Result<()> {
+        if mouse != self.mouse {
+            if mouse {
+                crossterm::execute!(stdout(), EnableMouseCapture)?;
+            } else {
+                crossterm::execute!(stdout(), DisableMouseCapture)?;
+            }
+
+            self.mouse = mouse;
+        }
+
+        Ok(())
+    }
+
     pub fn paste(mut self, paste: bool) -> Self {
         self.paste = paste;
         self